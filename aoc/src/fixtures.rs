@@ -0,0 +1,140 @@
+//! The manifest of expected answers for every day, see `fixtures.txt`.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use aoc_common::ParseError;
+
+/// Root of the repository, which fixture paths are relative to
+pub const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Path of the manifest
+pub const MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures.txt");
+
+/// An input file along with the answers it is expected to produce
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u8,
+    pub file: String,
+    /// Expected answers for part 1 and 2, if known
+    pub answers: [Option<String>; 2],
+    /// Slow fixtures are only checked on demand
+    pub slow: bool,
+}
+
+impl Fixture {
+    pub fn path(&self) -> PathBuf {
+        PathBuf::from(ROOT).join(&self.file)
+    }
+
+    /// Parts with a known answer
+    pub fn parts(&self) -> Vec<u8> {
+        (1..=2)
+            .filter(|&part| self.answers[part as usize - 1].is_some())
+            .collect()
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.answers[part as usize - 1].as_deref()
+    }
+}
+
+impl fmt::Display for Fixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let answer = |part: u8| self.expected(part).unwrap_or("-").to_string();
+
+        let line = format!(
+            "{:<2} {:<22} {:<11} {:<15} {}",
+            self.day,
+            self.file,
+            answer(1),
+            answer(2),
+            if self.slow { "slow" } else { "" }
+        );
+
+        write!(f, "{}", line.trim_end())
+    }
+}
+
+/// Parses a manifest line like "5  day_5/input.txt  51752125  -  slow"
+fn parse_fixture(line: &str) -> Result<Fixture, ParseError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+
+    if !(4..=5).contains(&parts.len()) {
+        return Err(ParseError::at_token(
+            "expected `<day> <file> <part 1> <part 2> [slow]`",
+            line,
+            line,
+        ));
+    }
+
+    let day: u8 = aoc_common::parse_token(line, parts[0], "invalid day")?;
+    let answer = |answer: &str| (answer != "-").then(|| answer.to_string());
+
+    let slow = match parts.get(4) {
+        None => false,
+        Some(&"slow") => true,
+        Some(flag) => {
+            return Err(ParseError::at_token(
+                format!("unknown flag `{flag}`"),
+                line,
+                flag,
+            ))
+        }
+    };
+
+    Ok(Fixture {
+        day,
+        file: parts[1].to_string(),
+        answers: [answer(parts[2]), answer(parts[3])],
+        slow,
+    })
+}
+
+/// Parses the manifest. Empty lines and lines starting with `#` are skipped
+pub fn parse_manifest(contents: &str) -> Result<Vec<Fixture>, ParseError> {
+    aoc_common::numbered_lines(contents)
+        .into_iter()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| {
+            parse_fixture(line).map_err(|error| error.at_line(number).with_source_line(line))
+        })
+        .collect()
+}
+
+/// Adds fixtures to the manifest, replacing the entries for the same files.
+/// New entries are placed after the last one of the same day
+pub fn update_manifest(contents: &str, fixtures: &[Fixture]) -> Result<String, ParseError> {
+    let mut lines: Vec<(Option<u8>, String)> = Vec::new();
+
+    for (number, line) in aoc_common::numbered_lines(contents) {
+        if line.is_empty() || line.starts_with('#') {
+            lines.push((None, line.to_string()));
+            continue;
+        }
+
+        let fixture = parse_fixture(line).map_err(|error| error.at_line(number))?;
+
+        if !fixtures.iter().any(|new| new.file == fixture.file) {
+            lines.push((Some(fixture.day), line.to_string()));
+        }
+    }
+
+    for fixture in fixtures {
+        let position = lines
+            .iter()
+            .rposition(|(day, _)| day.is_some_and(|day| day <= fixture.day))
+            .map_or(lines.len(), |index| index + 1);
+
+        lines.insert(position, (Some(fixture.day), fixture.to_string()));
+    }
+
+    let mut result: String = lines
+        .into_iter()
+        .map(|(_, line)| line)
+        .collect::<Vec<String>>()
+        .join("\n");
+    result.push('\n');
+
+    Ok(result)
+}
@@ -0,0 +1,49 @@
+//! Registry of every solved day, so a single binary can run any of them.
+
+pub mod fixtures;
+pub mod puzzle;
+
+use aoc_common::bench::{self, Timings};
+use aoc_common::runner::{self, PartAnswer};
+use aoc_common::{ParseError, Solution};
+
+/// Solves the given parts of some input
+pub type SolveFn = fn(&str, &[u8]) -> Result<Vec<PartAnswer>, ParseError>;
+
+/// Times the given parts `runs` times, after some warmup runs
+pub type BenchFn = fn(&str, &[u8], usize, usize) -> Result<Vec<Timings>, ParseError>;
+
+/// A type erased `Solution`
+pub struct Day {
+    pub day: u8,
+    pub default_input: &'static str,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            default_input: S::DEFAULT_INPUT,
+            solve: runner::solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+}
+
+pub const DAYS: [Day; 9] = [
+    Day::of::<day_1::Day1>(),
+    Day::of::<day_2::Day2>(),
+    Day::of::<day_3::Day3>(),
+    Day::of::<day_4::Day4>(),
+    Day::of::<day_5::Day5>(),
+    Day::of::<day_6::Day6>(),
+    Day::of::<day_7::Day7>(),
+    Day::of::<day_8::Day8>(),
+    Day::of::<day_9::Day9>(),
+];
+
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
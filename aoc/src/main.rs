@@ -0,0 +1,397 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use aoc::fixtures::{self, Fixture};
+use aoc::Day;
+use aoc_common::bench::{self, BenchRecord, Comparison};
+use aoc_common::log;
+use aoc_common::runner::{self, Format, PartAnswer};
+
+const USAGE: &str = "Usage:
+    aoc run <day> [-v|-vv] [--part <1|2>] [--format <text|json>] [input...]
+    aoc run --all [-v|-vv] [--format <text|json>]
+    aoc extract <day>|--all
+    aoc bench <day> [--part <1|2>] [--runs <n>] [--warmup <n>]
+                    [--threshold <percent>] [--output <file>] [input]
+
+Use `-` as input to read from stdin";
+
+/// Where bench results are kept between runs
+const BENCH_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../bench_output.txt");
+
+enum Command {
+    Run {
+        day: u8,
+        parts: Vec<u8>,
+        filenames: Vec<String>,
+        format: Format,
+    },
+    RunAll {
+        format: Format,
+    },
+    Extract {
+        days: Vec<u8>,
+    },
+    Bench(BenchOptions),
+}
+
+struct BenchOptions {
+    day: u8,
+    parts: Vec<u8>,
+    runs: usize,
+    warmup: usize,
+    /// Slowdown of the median, in percent, considered a regression
+    threshold: f64,
+    output: String,
+    filename: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]),
+        Some("extract") => parse_extract_args(&args[1..]),
+        Some("bench") => parse_bench_args(&args[1..]),
+        Some(other) => Err(format!("Unknown command: {other}")),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+fn parse_extract_args(args: &[String]) -> Result<Command, String> {
+    match args {
+        [all] if all == "--all" => Ok(Command::Extract {
+            days: aoc::DAYS.iter().map(|day| day.day).collect(),
+        }),
+        [day] => {
+            let day = day.parse().map_err(|_| format!("Invalid day: {day}"))?;
+            Ok(Command::Extract { days: vec![day] })
+        }
+        _ => Err("Expected a single day or --all".to_string()),
+    }
+}
+
+fn parse_part(part: Option<&String>) -> Result<Vec<u8>, String> {
+    let part = part.ok_or("Missing value for --part")?;
+
+    match part.as_str() {
+        "1" => Ok(vec![1]),
+        "2" => Ok(vec![2]),
+        _ => Err(format!("Invalid part: {part}")),
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("Missing value for {flag}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {flag}: {value}"))
+}
+
+fn parse_bench_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    let mut day: Option<u8> = None;
+    let mut options = BenchOptions {
+        day: 0,
+        parts: vec![1, 2],
+        runs: 10,
+        warmup: 2,
+        threshold: 10.0,
+        output: BENCH_OUTPUT.to_string(),
+        filename: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => options.parts = parse_part(args.next())?,
+            "--runs" => options.runs = parse_value(arg, args.next())?,
+            "--warmup" => options.warmup = parse_value(arg, args.next())?,
+            "--threshold" => options.threshold = parse_value(arg, args.next())?,
+            "--output" => options.output = parse_value(arg, args.next())?,
+            _ if day.is_none() => day = Some(parse_value("day", Some(arg))?),
+            _ if options.filename.is_none() => options.filename = Some(arg.clone()),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    if options.runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+
+    options.day = day.ok_or("Missing day")?;
+
+    Ok(Command::Bench(options))
+}
+
+fn parse_run_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    let mut day: Option<u8> = None;
+    let mut parts: Vec<u8> = vec![1, 2];
+    let mut filenames: Vec<String> = Vec::new();
+    let mut all = false;
+    let mut format = Format::default();
+
+    while let Some(arg) = args.next() {
+        if let Some(verbosity) = log::verbosity_flag(arg) {
+            log::set_verbosity(log::verbosity() + verbosity);
+            continue;
+        }
+
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => parts = parse_part(args.next())?,
+            "--format" => format = parse_value(arg, args.next())?,
+            _ if day.is_none() && !all => {
+                let number = arg.parse().map_err(|_| format!("Invalid day: {arg}"))?;
+                day = Some(number);
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unexpected argument: {arg}")),
+            _ => filenames.push(arg.clone()),
+        }
+    }
+
+    if all && !filenames.is_empty() {
+        return Err("--all does not take an input".to_string());
+    }
+
+    match (all, day) {
+        (true, None) => Ok(Command::RunAll { format }),
+        (true, Some(_)) => Err("--all does not take a day".to_string()),
+        (false, Some(day)) => Ok(Command::Run {
+            day,
+            parts,
+            filenames,
+            format,
+        }),
+        (false, None) => Err("Missing day".to_string()),
+    }
+}
+
+fn print_header() {
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+}
+
+fn print_answers(day: &Day, filename: &str, answers: &[PartAnswer], format: Format) {
+    for answer in answers {
+        if format == Format::Json {
+            println!("{}", answer.to_json(day.day, filename));
+            continue;
+        }
+
+        println!(
+            "{:>3}  {:>4}  {:<20}  {:>12}",
+            day.day,
+            answer.part,
+            answer.answer,
+            format!("{:.2?}", answer.elapsed)
+        );
+    }
+}
+
+/// Solves every file and prints the answers. Returns whether all of them
+/// could be solved
+fn run(day: &Day, parts: &[u8], filenames: &[&str], format: Format) -> bool {
+    runner::for_each_input(
+        filenames,
+        format,
+        |filename| runner::solve_file(day.solve, filename, parts),
+        |filename, answers| print_answers(day, filename, &answers, format),
+    )
+}
+
+/// Writes the examples found in the day's puzzle description as fixtures.
+/// Days without a description are skipped
+fn extract(day: u8) -> Result<Vec<Fixture>, String> {
+    let directory = format!("day_{day}");
+    let puzzle_path = Path::new(fixtures::ROOT).join(&directory).join("puzzle.md");
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        return Ok(Vec::new());
+    };
+
+    aoc::puzzle::extract_examples(&markdown)
+        .into_iter()
+        .enumerate()
+        .map(|(index, example)| {
+            let file = format!("{directory}/example_{}.txt", index + 1);
+            let path = Path::new(fixtures::ROOT).join(&file);
+
+            fs::write(&path, &example.input)
+                .map_err(|error| format!("Could not write {}: {error}", path.display()))?;
+
+            Ok(Fixture {
+                day,
+                file,
+                answers: example.answers,
+                slow: false,
+            })
+        })
+        .collect()
+}
+
+fn extract_all(days: &[u8]) -> Result<(), String> {
+    let mut extracted: Vec<Fixture> = Vec::new();
+
+    for &day in days {
+        extracted.extend(extract(day)?);
+    }
+
+    let manifest = fs::read_to_string(fixtures::MANIFEST)
+        .map_err(|error| format!("Could not read the manifest: {error}"))?;
+    let manifest = fixtures::update_manifest(&manifest, &extracted)
+        .map_err(|error| error.in_file(fixtures::MANIFEST).diagnostic())?;
+
+    fs::write(fixtures::MANIFEST, manifest)
+        .map_err(|error| format!("Could not write the manifest: {error}"))?;
+
+    for fixture in extracted {
+        println!("{fixture}");
+    }
+
+    Ok(())
+}
+
+fn format_change(comparison: &Comparison) -> String {
+    match (comparison.change(), comparison.regression) {
+        (Some(change), true) => format!("{change:+.1}%  REGRESSION"),
+        (Some(change), false) => format!("{change:+.1}%"),
+        (None, _) => "-".to_string(),
+    }
+}
+
+/// Name of an input in the bench records: its path from the workspace root
+/// when it is in it, so the same file is always named the same way
+fn bench_input_name(filename: &str) -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let relative = fs::canonicalize(filename).ok().and_then(|path| {
+        let root = fs::canonicalize(root).ok()?;
+        Some(path.strip_prefix(root).ok()?.display().to_string())
+    });
+
+    relative.unwrap_or_else(|| aoc_common::input_name(filename).to_string())
+}
+
+fn run_bench(day: &Day, options: &BenchOptions) -> Result<(), String> {
+    let filename = options.filename.as_deref().unwrap_or(day.default_input);
+    let contents = runner::read_input_or_exit(filename);
+    let input = bench_input_name(filename);
+
+    let timings = (day.bench)(&contents, &options.parts, options.runs, options.warmup)
+        .map_err(|error| error.in_file(filename).diagnostic())?;
+
+    let records: Vec<BenchRecord> = timings
+        .iter()
+        .map(|timings| BenchRecord::new(day.day, &input, timings))
+        .collect();
+
+    // A missing file just means there is no previous run
+    let previous = fs::read_to_string(&options.output).unwrap_or_default();
+    let previous = bench::parse_records(&previous)
+        .map_err(|error| error.in_file(&options.output).diagnostic())?;
+
+    println!(
+        "Day {} on {input} ({} runs, {} warmup)",
+        day.day, options.runs, options.warmup
+    );
+    println!(
+        "{:<6}  {:>12}  {:>12}  {:>12}  {:>12}  Change",
+        "Phase", "Min", "Median", "Max", "Previous"
+    );
+
+    for comparison in bench::compare(&records, &previous, options.threshold) {
+        let record = &comparison.record;
+        let previous = comparison
+            .previous
+            .map_or("-".to_string(), |previous| format!("{previous:.2?}"));
+
+        println!(
+            "{:<6}  {:>12}  {:>12}  {:>12}  {:>12}  {}",
+            record.phase,
+            format!("{:.2?}", record.min),
+            format!("{:.2?}", record.median),
+            format!("{:.2?}", record.max),
+            previous,
+            format_change(&comparison)
+        );
+    }
+
+    let mut output = String::from("# <day> <phase> <min ns> <median ns> <max ns> <runs> <input>\n");
+
+    for record in bench::merge_records(&previous, &records) {
+        output.push_str(&format!("{record}\n"));
+    }
+
+    fs::write(&options.output, output)
+        .map_err(|error| format!("Could not write {}: {error}", options.output))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("{error}\n\n{USAGE}");
+        process::exit(2);
+    });
+
+    match command {
+        Command::Run {
+            day,
+            parts,
+            filenames,
+            format,
+        } => {
+            let Some(day) = aoc::find_day(day) else {
+                eprintln!("Day {day} is not solved yet");
+                process::exit(1);
+            };
+
+            let filenames = runner::inputs(&filenames, day.default_input);
+
+            if format == Format::Text {
+                print_header();
+            }
+
+            if !run(day, &parts, &filenames, format) {
+                process::exit(1);
+            }
+        }
+        Command::RunAll { format } => {
+            if format == Format::Text {
+                print_header();
+            }
+
+            let mut solved = true;
+
+            for day in aoc::DAYS.iter() {
+                solved &= run(day, &[1, 2], &[day.default_input], format);
+            }
+
+            if !solved {
+                process::exit(1);
+            }
+        }
+        Command::Bench(options) => {
+            let Some(day) = aoc::find_day(options.day) else {
+                eprintln!("Day {} is not solved yet", options.day);
+                process::exit(1);
+            };
+
+            if let Err(error) = run_bench(day, &options) {
+                eprintln!("{error}");
+                process::exit(1);
+            }
+        }
+        Command::Extract { days } => {
+            if let Err(error) = extract_all(&days) {
+                eprintln!("{error}");
+                process::exit(1);
+            }
+        }
+    }
+}
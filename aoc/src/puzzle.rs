@@ -0,0 +1,171 @@
+//! Extraction of the examples, and the answers they are stated to produce,
+//! from the puzzle descriptions saved as `day_N/puzzle.md`.
+//!
+//! An example is a fenced block introduced by a paragraph saying "For
+//! example". Its answer is the last emphasized number (like `*114*`) written
+//! before the next example is introduced. When the second part doesn't give a
+//! new example, its answer is for the last example of the first part.
+
+/// An example input along with its stated answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// Answers for part 1 and 2, if stated
+    pub answers: [Option<String>; 2],
+}
+
+#[derive(Debug, PartialEq)]
+enum Chunk<'a> {
+    Paragraph(String),
+    Block(Vec<&'a str>),
+}
+
+/// Splits markdown into paragraphs and fenced blocks
+fn chunks(markdown: &str) -> Vec<Chunk<'_>> {
+    let mut chunks: Vec<Chunk> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        match block.as_mut() {
+            Some(lines) if !is_fence => {
+                lines.push(line);
+                continue;
+            }
+            Some(_) => {
+                chunks.push(Chunk::Block(block.take().unwrap_or_default()));
+                continue;
+            }
+            None => {}
+        }
+
+        if !is_fence && !line.trim().is_empty() {
+            paragraph.push(line);
+            continue;
+        }
+
+        if !paragraph.is_empty() {
+            chunks.push(Chunk::Paragraph(paragraph.join("\n")));
+            paragraph.clear();
+        }
+
+        if is_fence {
+            block = Some(Vec::new());
+        }
+    }
+
+    if !paragraph.is_empty() {
+        chunks.push(Chunk::Paragraph(paragraph.join("\n")));
+    }
+
+    chunks
+}
+
+/// Numbers written as `*<number>*` in a paragraph
+fn emphasized_numbers(paragraph: &str) -> Vec<String> {
+    paragraph
+        .split("`*")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("*`"))
+        .map(|(value, _)| value)
+        .filter(|value| value.parse::<i64>().is_ok())
+        .map(String::from)
+        .collect()
+}
+
+fn is_example_intro(paragraph: &str) -> bool {
+    paragraph.to_lowercase().contains("for example")
+}
+
+/// The part up to the actual puzzle answer, which is not an example answer
+fn before_puzzle_answer(part: &str) -> &str {
+    ["Your puzzle answer was", "\nAnswer:"]
+        .iter()
+        .filter_map(|marker| part.find(marker))
+        .min()
+        .map_or(part, |end| &part[..end])
+}
+
+/// Examples introduced in a part and their answers. Also returns the last
+/// number stated in the part, for when it doesn't bring its own example
+fn part_examples(part: &str) -> (Vec<(String, Option<String>)>, Option<String>) {
+    let chunks = chunks(before_puzzle_answer(part));
+    let mut examples: Vec<(String, Option<String>)> = Vec::new();
+    let mut last_answer: Option<String> = None;
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        match chunk {
+            Chunk::Paragraph(paragraph) => {
+                let introduces_example = is_example_intro(paragraph)
+                    && matches!(chunks.get(index + 1), Some(Chunk::Block(_)));
+
+                // The answer of the previous example can't be after the next one is introduced
+                if introduces_example {
+                    if let Some(example) = examples.last_mut() {
+                        example.1 = last_answer.take();
+                    }
+                }
+
+                if let Some(answer) = emphasized_numbers(paragraph).pop() {
+                    last_answer = Some(answer);
+                }
+            }
+            Chunk::Block(lines) => {
+                let introduced = matches!(
+                    index.checked_sub(1).and_then(|previous| chunks.get(previous)),
+                    Some(Chunk::Paragraph(paragraph)) if is_example_intro(paragraph)
+                );
+
+                if introduced {
+                    let input = lines.join("\n").trim_end().to_string() + "\n";
+                    examples.push((input, None));
+                }
+            }
+        }
+    }
+
+    match examples.last_mut() {
+        Some(example) => {
+            example.1 = last_answer.take();
+            (examples, None)
+        }
+        None => (examples, last_answer),
+    }
+}
+
+/// Extracts every example of the puzzle along with its stated answers
+pub fn extract_examples(markdown: &str) -> Vec<Example> {
+    let (part_one, part_two) = match markdown.find("--- Part Two ---") {
+        Some(index) => markdown.split_at(index),
+        None => (markdown, ""),
+    };
+
+    let (first_examples, _) = part_examples(part_one);
+    let (second_examples, second_answer) = part_examples(part_two);
+
+    let mut examples: Vec<Example> = first_examples
+        .into_iter()
+        .map(|(input, answer)| Example {
+            input,
+            answers: [answer, None],
+        })
+        .collect();
+
+    if let (Some(answer), Some(example)) = (second_answer, examples.last_mut()) {
+        example.answers[1] = Some(answer);
+    }
+
+    for (input, answer) in second_examples {
+        match examples.iter_mut().find(|example| example.input == input) {
+            Some(example) => example.answers[1] = answer,
+            None => examples.push(Example {
+                input,
+                answers: [None, answer],
+            }),
+        }
+    }
+
+    examples
+}
@@ -0,0 +1,80 @@
+//! Runs every day against the inputs listed in `fixtures.txt` and checks the
+//! answers haven't changed.
+
+use std::fs;
+
+use aoc::fixtures::{self, Fixture};
+
+fn load_fixtures() -> Vec<Fixture> {
+    let contents = fs::read_to_string(fixtures::MANIFEST).expect("Could not read the manifest");
+
+    fixtures::parse_manifest(&contents)
+        .unwrap_or_else(|error| panic!("{}", error.in_file(fixtures::MANIFEST).diagnostic()))
+}
+
+/// Checks every fixture that is (or isn't) slow, reporting all the mismatches
+/// at once
+fn check_fixtures(slow: bool) {
+    let mut failures: Vec<String> = Vec::new();
+
+    for fixture in load_fixtures().iter().filter(|f| f.slow == slow) {
+        let day = aoc::find_day(fixture.day)
+            .unwrap_or_else(|| panic!("Day {} is not solved yet", fixture.day));
+
+        let path = fixture.path();
+        let contents = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("Could not read {}: {error}", path.display()));
+
+        let answers = match (day.solve)(&contents, &fixture.parts()) {
+            Ok(answers) => answers,
+            Err(error) => {
+                failures.push(error.in_file(&fixture.file).diagnostic());
+                continue;
+            }
+        };
+
+        for answer in answers {
+            let expected = fixture.expected(answer.part).unwrap_or_default();
+
+            if answer.answer != expected {
+                failures.push(format!(
+                    "day {} part {} on {}: expected {expected}, got {}",
+                    fixture.day, answer.part, fixture.file, answer.answer
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+#[test]
+fn golden_answers() {
+    check_fixtures(false);
+}
+
+#[test]
+#[ignore = "takes minutes, run with `cargo test -- --ignored`"]
+fn slow_golden_answers() {
+    check_fixtures(true);
+}
+
+#[test]
+fn every_day_has_fixtures() {
+    let fixtures = load_fixtures();
+
+    for day in aoc::DAYS.iter() {
+        assert!(
+            fixtures.iter().any(|fixture| fixture.day == day.day),
+            "Day {} has no fixtures",
+            day.day
+        );
+    }
+}
+
+#[test]
+fn manifest_errors_point_at_the_line() {
+    let error = fixtures::parse_manifest("# comment\n1 day_1/test.txt 209\n").unwrap_err();
+
+    assert_eq!(error.line, Some(2));
+}
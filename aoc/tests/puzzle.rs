@@ -0,0 +1,74 @@
+use aoc::puzzle::{extract_examples, Example};
+
+const PUZZLE: &str = "\\--- Day 0: Test ---
+----------
+
+Sum the numbers. For example:
+
+```
+1 2
+3 4
+
+```
+
+The first line adds up to `*3*` and the second one to `*7*`, so the sum is `*10*`.
+
+Your puzzle answer was `1234`.
+
+\\--- Part Two ---
+----------
+
+Now multiply them. With the same example:
+
+```
+1 2
+  2
+
+```
+
+The product is `*24*`.
+
+Answer:
+";
+
+fn example(input: &str, part1: Option<&str>, part2: Option<&str>) -> Example {
+    Example {
+        input: input.to_string(),
+        answers: [part1.map(String::from), part2.map(String::from)],
+    }
+}
+
+#[test]
+fn second_part_reuses_the_first_example() {
+    assert_eq!(
+        extract_examples(PUZZLE),
+        vec![example("1 2\n3 4\n", Some("10"), Some("24"))]
+    );
+}
+
+#[test]
+fn every_introduced_block_is_an_example() {
+    let puzzle = PUZZLE.replace("Now multiply them. With the same example:", "For example:");
+
+    assert_eq!(
+        extract_examples(&puzzle),
+        vec![
+            example("1 2\n3 4\n", Some("10"), None),
+            example("1 2\n  2\n", None, Some("24")),
+        ]
+    );
+}
+
+#[test]
+fn answers_stop_at_the_next_example() {
+    let puzzle = "For example:\n\n```\na\n```\n\nTakes `*2*` steps.\n\n\
+                  For example, this one takes `*6*` steps:\n\n```\nb\n```\n";
+
+    assert_eq!(
+        extract_examples(puzzle),
+        vec![
+            example("a\n", Some("2"), None),
+            example("b\n", Some("6"), None),
+        ]
+    );
+}
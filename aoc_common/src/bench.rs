@@ -0,0 +1,263 @@
+//! Timing of the parse and solve phases of a day, using only `std::time`.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{ParseError, Solution};
+
+/// Every measured duration of a phase: "parse", "part1" or "part2"
+#[derive(Debug, Clone)]
+pub struct Timings {
+    pub phase: String,
+    pub samples: Vec<Duration>,
+}
+
+impl Timings {
+    fn new(phase: &str) -> Self {
+        Timings {
+            phase: phase.to_string(),
+            samples: Vec::new(),
+        }
+    }
+
+    fn sorted(&self) -> Vec<Duration> {
+        let mut samples = self.samples.clone();
+        samples.sort();
+        samples
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let samples = self.sorted();
+
+        match samples.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 0 => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+            len => samples[len / 2],
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Runs the parser and the requested parts `runs` times, after `warmup` runs
+/// that are not measured. Stops at the first error of the parser or a part
+pub fn bench<S: Solution>(
+    contents: &str,
+    parts: &[u8],
+    runs: usize,
+    warmup: usize,
+) -> Result<Vec<Timings>, ParseError> {
+    let mut parse_timings = Timings::new("parse");
+    let mut part_timings: Vec<Timings> = parts
+        .iter()
+        .map(|part| Timings::new(&format!("part{part}")))
+        .collect();
+
+    for run in 0..warmup + runs {
+        let (input, elapsed) = timed(|| S::parse(contents));
+        let input = input?;
+        let measured = run >= warmup;
+
+        if measured {
+            parse_timings.samples.push(elapsed);
+        }
+
+        for (&part, timings) in parts.iter().zip(part_timings.iter_mut()) {
+            // black_box keeps the answer from being optimized away
+            let (answer, elapsed) = match part {
+                1 => timed(|| black_box(S::part1(&input))),
+                2 => timed(|| black_box(S::part2(&input))),
+                _ => panic!("Invalid part: {part}"),
+            };
+            answer?;
+
+            if measured {
+                timings.samples.push(elapsed);
+            }
+        }
+    }
+
+    let mut timings = vec![parse_timings];
+    timings.extend(part_timings);
+
+    Ok(timings)
+}
+
+/// Summary of the timings of a phase on an input, as saved between runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchRecord {
+    pub day: u8,
+    /// Name of the input file, which timings only compare within. `None`
+    /// for records saved before it was recorded: they are kept, but not
+    /// compared with anything
+    pub input: Option<String>,
+    pub phase: String,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub runs: usize,
+}
+
+impl BenchRecord {
+    pub fn new(day: u8, input: &str, timings: &Timings) -> Self {
+        BenchRecord {
+            day,
+            input: Some(input.to_string()),
+            phase: timings.phase.clone(),
+            min: timings.min(),
+            median: timings.median(),
+            max: timings.max(),
+            runs: timings.samples.len(),
+        }
+    }
+
+    /// Whether both records are about the same phase of a day on the same
+    /// known input
+    pub fn same_phase(&self, other: &BenchRecord) -> bool {
+        self.day == other.day
+            && self.input.is_some()
+            && self.input == other.input
+            && self.phase == other.phase
+    }
+}
+
+impl fmt::Display for BenchRecord {
+    /// Writes the record as
+    /// "<day> <phase> <min ns> <median ns> <max ns> <runs> <input>".
+    /// The input comes last as file names can have spaces, and is left out
+    /// when unknown
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.day,
+            self.phase,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos(),
+            self.runs
+        )?;
+
+        match &self.input {
+            Some(input) => write!(f, " {input}"),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_record(line: &str) -> Result<BenchRecord, ParseError> {
+    let parts: Vec<&str> = line.split_whitespace().take(6).collect();
+
+    // Everything after the run count, spaces included
+    let input = parts.get(5).map_or("", |runs| {
+        let end = runs.as_ptr() as usize - line.as_ptr() as usize + runs.len();
+        line[end..].trim()
+    });
+
+    if parts.len() != 6 {
+        return Err(ParseError::at_token(
+            "expected `<day> <phase> <min> <median> <max> <runs> [<input>]`",
+            line,
+            line,
+        ));
+    }
+
+    let nanos = |token: &str| -> Result<Duration, ParseError> {
+        crate::parse_token(line, token, "invalid duration").map(Duration::from_nanos)
+    };
+
+    Ok(BenchRecord {
+        day: crate::parse_token(line, parts[0], "invalid day")?,
+        input: (!input.is_empty()).then(|| input.to_string()),
+        phase: parts[1].to_string(),
+        min: nanos(parts[2])?,
+        median: nanos(parts[3])?,
+        max: nanos(parts[4])?,
+        runs: crate::parse_token(line, parts[5], "invalid run count")?,
+    })
+}
+
+/// Parses saved records, one per line. Lines starting with `#` are skipped.
+/// Records written before the input was saved have none
+pub fn parse_records(contents: &str) -> Result<Vec<BenchRecord>, ParseError> {
+    crate::numbered_lines(contents)
+        .into_iter()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| {
+            parse_record(line).map_err(|error| error.at_line(number).with_source_line(line))
+        })
+        .collect()
+}
+
+/// Replaces the previous records of the phases that were run again on the
+/// same input
+pub fn merge_records(previous: &[BenchRecord], new: &[BenchRecord]) -> Vec<BenchRecord> {
+    let mut records: Vec<BenchRecord> = previous
+        .iter()
+        .filter(|record| !new.iter().any(|new| new.same_phase(record)))
+        .cloned()
+        .collect();
+
+    records.extend(new.iter().cloned());
+    records.sort_by(|a, b| (a.day, &a.input, &a.phase).cmp(&(b.day, &b.input, &b.phase)));
+
+    records
+}
+
+/// A new record compared with the previous run of the same phase on the
+/// same input
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub record: BenchRecord,
+    pub previous: Option<Duration>,
+    /// Whether the median got slower by more than the threshold
+    pub regression: bool,
+}
+
+impl Comparison {
+    /// Change of the median from the previous run, in percent
+    pub fn change(&self) -> Option<f64> {
+        let previous = self.previous?.as_secs_f64();
+
+        if previous == 0.0 {
+            return None;
+        }
+
+        Some((self.record.median.as_secs_f64() - previous) / previous * 100.0)
+    }
+}
+
+/// Compares new records with the previous ones on the same input. A phase
+/// regressed if its median is more than `threshold` percent slower
+pub fn compare(new: &[BenchRecord], previous: &[BenchRecord], threshold: f64) -> Vec<Comparison> {
+    new.iter()
+        .map(|record| {
+            let previous = previous
+                .iter()
+                .find(|old| old.same_phase(record))
+                .map(|old| old.median);
+
+            let mut comparison = Comparison {
+                record: record.clone(),
+                previous,
+                regression: false,
+            };
+
+            comparison.regression = comparison.change().is_some_and(|change| change > threshold);
+            comparison
+        })
+        .collect()
+}
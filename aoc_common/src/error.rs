@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error found while parsing the puzzle input.
+/// Points at the offending token, so it can be reported like rustc does:
+///
+/// ```text
+/// error: invalid cube count
+///  --> day_2/input.txt:3:9
+///   |
+/// 3 | Game 3: x blue
+///   |         ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub token: String,
+    /// Byte offset of the token in its line
+    pub column: usize,
+    /// 1 based line number, if known
+    pub line: Option<usize>,
+    pub file: Option<String>,
+    /// Content of the line the error was found in
+    pub source_line: Option<String>,
+}
+
+/// Byte offset of `part` in `line`, if `part` is a slice of `line`.
+/// Falls back to searching for it otherwise
+fn offset_in(line: &str, part: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+
+    if line_start <= part_start && part_start + part.len() <= line_start + line.len() {
+        part_start - line_start
+    } else {
+        line.find(part).unwrap_or(0)
+    }
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>, token: impl Into<String>, column: usize) -> Self {
+        ParseError {
+            message: message.into(),
+            token: token.into(),
+            column,
+            line: None,
+            file: None,
+            source_line: None,
+        }
+    }
+
+    /// Error about `token`, which should be a slice of `line`
+    pub fn at_token(message: impl Into<String>, line: &str, token: &str) -> Self {
+        ParseError::new(message, token, offset_in(line, token)).with_source_line(line)
+    }
+
+    /// Error about something missing at the end of `line`
+    pub fn at_end(message: impl Into<String>, line: &str) -> Self {
+        ParseError::new(message, "", line.len()).with_source_line(line)
+    }
+
+    /// Sets the line number, unless it is already known
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Moves the error `offset` lines down.
+    /// Used when the line number is relative to a block of the input
+    pub fn offset_lines(mut self, offset: usize) -> Self {
+        self.line = self.line.map(|line| line + offset);
+        self
+    }
+
+    /// Moves the error `offset` bytes to the right
+    pub fn offset_columns(mut self, offset: usize) -> Self {
+        self.column += offset;
+        self
+    }
+
+    /// Makes an error found while parsing `part` relative to `line`,
+    /// `part` being a slice of it
+    pub fn within(self, line: &str, part: &str) -> Self {
+        let mut error = self.offset_columns(offset_in(line, part));
+        error.source_line = Some(line.to_string());
+        error
+    }
+
+    /// Sets the line the error was found in, unless it is already known
+    pub fn with_source_line(mut self, line: &str) -> Self {
+        self.source_line.get_or_insert_with(|| line.to_string());
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// 1 based column, counted in characters
+    fn display_column(&self) -> usize {
+        let prefix = self
+            .source_line
+            .as_deref()
+            .and_then(|line| line.get(..self.column));
+
+        match prefix {
+            Some(prefix) => prefix.chars().count() + 1,
+            None => self.column + 1,
+        }
+    }
+
+    /// Where the error is: the file, line and column, or only the file for
+    /// errors about the input as a whole
+    fn location(&self) -> Option<String> {
+        let Some(line) = self.line else {
+            return self.file.clone();
+        };
+        let file = self.file.as_deref().unwrap_or("<input>");
+
+        Some(format!("{file}:{line}:{}", self.display_column()))
+    }
+
+    /// Renders the error as a multi line diagnostic, with a caret under the
+    /// offending token
+    pub fn diagnostic(&self) -> String {
+        let mut result = format!("error: {}", self.message);
+
+        let Some(location) = self.location() else {
+            return result;
+        };
+
+        let line_number = self.line.map(|line| line.to_string());
+        let gutter = " ".repeat(line_number.as_ref().map_or(1, String::len));
+
+        result.push_str(&format!("\n{gutter}--> {location}"));
+
+        if let (Some(line_number), Some(source_line)) = (line_number, &self.source_line) {
+            let padding = " ".repeat(self.display_column() - 1);
+            let carets = "^".repeat(self.token.chars().count().max(1));
+
+            result.push_str(&format!("\n{gutter} |"));
+            result.push_str(&format!("\n{line_number} | {source_line}"));
+            result.push_str(&format!("\n{gutter} | {padding}{carets}"));
+        }
+
+        result
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location() {
+            Some(location) => write!(f, "{location}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,249 @@
+//! A rectangular grid of cells addressed by `(row, col)`.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// Position of a cell, as `(row, col)`
+pub type Position = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours: up, left, right and down
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the 8 neighbours including diagonals, in reading order
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (index, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::at_end(
+                    format!("expected a row of {width} cells, found {}", row.len()),
+                    "",
+                )
+                .at_line(index + 1));
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses every non empty line of `contents` into a row, converting each
+    /// character with `parse_cell`
+    pub fn parse_with<F>(contents: &str, parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Result<T, ParseError>,
+    {
+        let mut width: Option<usize> = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (number, line) in crate::numbered_lines(contents) {
+            if line.is_empty() {
+                continue;
+            }
+
+            let tag = |error: ParseError| error.at_line(number).with_source_line(line);
+
+            let row_width = line.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                let message = format!("expected a row of {} cells", width.unwrap_or_default());
+                return Err(tag(ParseError::at_end(message, line)));
+            }
+
+            for (column, c) in line.char_indices() {
+                let cell = parse_cell(c).map_err(|error| tag(error.offset_columns(column)))?;
+                cells.push(cell);
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(row, col)` is inside the grid
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// The cell at `(row, col)`, if it is inside the grid
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        Some(&mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// The cell at `position` moved by `(row, col)` steps, if still inside
+    /// the grid
+    pub fn offset(&self, (row, col): Position, (d_row, d_col): (isize, isize)) -> Option<Position> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+
+        self.contains((row, col)).then_some((row, col))
+    }
+
+    /// Positions of the up to 4 orthogonal neighbours inside the grid
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&step| self.offset(position, step))
+    }
+
+    /// Positions of the up to 8 neighbours inside the grid, diagonals included
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&step| self.offset(position, step))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = if col < self.width {
+            &self.cells[col..]
+        } else {
+            &[]
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Every cell along with its position, in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| ((index / self.width, index % self.width), cell))
+    }
+
+    /// Builds a grid of the same size by converting every cell
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swaps rows and columns
+    pub fn transpose(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses every non empty line of `contents` into a row of characters
+    pub fn parse_chars(contents: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(contents, Ok)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Writes a row per line. The width, as in `{:3}`, is applied to every
+    /// cell, so numbers line up in columns
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                match f.width() {
+                    Some(width) => write!(f, "{cell:>width$}")?,
+                    None => write!(f, "{cell}")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
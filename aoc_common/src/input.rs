@@ -0,0 +1,78 @@
+use std::fs;
+use std::io::{self, Read};
+
+/// File name standing for the standard input
+pub const STDIN: &str = "-";
+
+/// Reads the whole puzzle input into a string. `-` reads the standard input
+pub fn read_input(filename: &str) -> io::Result<String> {
+    if filename == STDIN {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        return Ok(contents);
+    }
+
+    fs::read_to_string(filename)
+}
+
+/// Name of the input to show in messages
+pub fn input_name(filename: &str) -> &str {
+    if filename == STDIN {
+        "<stdin>"
+    } else {
+        filename
+    }
+}
+
+/// Splits the input into trimmed lines
+pub fn lines(contents: &str) -> Vec<&str> {
+    contents.lines().map(|line| line.trim()).collect()
+}
+
+/// Splits the input into trimmed lines, skipping the empty ones
+pub fn non_empty_lines(contents: &str) -> Vec<&str> {
+    contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Splits the input into trimmed lines along with their 1 based line number
+pub fn numbered_lines(contents: &str) -> Vec<(usize, &str)> {
+    contents
+        .lines()
+        .map(|line| line.trim())
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .collect()
+}
+
+/// Groups lines into blocks separated by blank lines.
+/// Each block is returned trimmed, with its lines joined by '\n'
+pub fn blocks(lines: &[&str]) -> Vec<String> {
+    numbered_blocks(lines)
+        .into_iter()
+        .map(|(_, block)| block)
+        .collect()
+}
+
+/// Same as `blocks`, along with the index in `lines` of the first line of
+/// every block
+pub fn numbered_blocks(lines: &[&str]) -> Vec<(usize, String)> {
+    let mut blocks: Vec<(usize, String)> = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (index, line) in lines.iter().chain(std::iter::once(&"")).enumerate() {
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some(index),
+            (true, Some(first)) => {
+                blocks.push((first, lines[first..index].join("\n").trim().to_string()));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    blocks
+}
@@ -0,0 +1,35 @@
+//! Just enough JSON writing for the machine-readable outputs, without
+//! pulling in a serialization crate.
+
+use std::fmt::Write;
+
+/// Quotes and escapes a string as a JSON string literal
+pub fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Writes integers as JSON numbers and anything else as a string
+pub fn value(value: &str) -> String {
+    match value.parse::<i128>() {
+        Ok(number) => number.to_string(),
+        Err(_) => quote(value),
+    }
+}
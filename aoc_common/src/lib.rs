@@ -0,0 +1,19 @@
+//! Helpers shared by every day of the 2023 advent of code.
+
+pub mod bench;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod math;
+pub mod parse;
+pub mod runner;
+pub mod solution;
+
+pub use error::ParseError;
+pub use input::{
+    blocks, input_name, lines, non_empty_lines, numbered_blocks, numbered_lines, read_input,
+};
+pub use parse::{parse_lines, parse_lines_with, parse_numbers, parse_token};
+pub use solution::Solution;
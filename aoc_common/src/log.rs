@@ -0,0 +1,83 @@
+//! Opt-in diagnostic output, silent unless the verbosity is raised with
+//! `-v` (debug) or `-vv` (trace).
+//!
+//! Messages go to stderr so they never mix with the answers.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// How much diagnostic output is printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Summaries of intermediate results, shown with `-v`
+    Debug = 1,
+    /// Step by step output, shown with `-vv`
+    Trace = 2,
+}
+
+impl Level {
+    pub fn label(self) -> &'static str {
+        match self {
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+/// Sets the verbosity: 0 is silent, 1 shows debug messages and 2 or more
+/// also shows trace messages
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn enabled(level: Level) -> bool {
+    verbosity() >= level as u8
+}
+
+/// Counts the verbosity flags in `arg`: `-v` is 1, `-vv` is 2 and so on.
+/// Returns `None` for any other argument
+pub fn verbosity_flag(arg: &str) -> Option<u8> {
+    let count = arg.strip_prefix('-')?;
+
+    if count.is_empty() || !count.chars().all(|c| c == 'v') {
+        return None;
+    }
+
+    Some(count.len() as u8)
+}
+
+/// Prints a message to stderr if `level` is enabled
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level) {
+            eprintln!(
+                "[{} {}] {}",
+                $level.label(),
+                module_path!(),
+                format_args!($($arg)*)
+            );
+        }
+    };
+}
+
+/// Prints a message to stderr with `-v` or more
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::log::Level::Debug, $($arg)*)
+    };
+}
+
+/// Prints a message to stderr with `-vv` or more
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::log::Level::Trace, $($arg)*)
+    };
+}
@@ -0,0 +1,227 @@
+//! Number theory and algebra helpers: gcd, lcm, the chinese remainder
+//! theorem, exact integer roots and finite differences.
+//!
+//! Everything is computed with integers, and operations that can overflow
+//! return `None` instead of wrapping.
+
+/// Greatest common divisor. `gcd(0, 0)` is 0
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let mut a = a;
+    let mut b = b;
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, or `None` if it doesn't fit in a `u64`.
+/// `lcm(0, n)` is 0
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all the values, 1 if there are none
+pub fn checked_lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Extended euclidean algorithm: returns `(g, x, y)` such that
+/// `a * x + b * y == g`, where `g` is the non negative gcd of `a` and `b`.
+/// `None` if `g` doesn't fit in an `i64`, which only happens for `i64::MIN`
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+
+    Some((g.try_into().ok()?, x.try_into().ok()?, y.try_into().ok()?))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Chinese remainder theorem: the smallest non negative `x` satisfying
+/// `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, along
+/// with the lcm of the moduli, which all the other solutions differ by.
+///
+/// Moduli don't need to be coprime. Returns `None` if the congruences
+/// contradict each other, a modulus is not positive, or the result
+/// doesn't fit in an `i64`
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for &(residue, other_modulus) in congruences {
+        if other_modulus <= 0 {
+            return None;
+        }
+
+        let (residue, other_modulus) = (residue as i128, other_modulus as i128);
+
+        // x + modulus * k ≡ residue (mod other_modulus)
+        let (g, inverse, _) = extended_gcd_wide(modulus, other_modulus);
+        let difference = residue - x;
+
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = other_modulus / g;
+        let k = (difference / g % step * inverse % step).rem_euclid(step);
+
+        x += modulus * k;
+        modulus = modulus.checked_mul(step)?;
+
+        if modulus > i64::MAX as i128 {
+            return None;
+        }
+
+        x = x.rem_euclid(modulus);
+    }
+
+    Some((x as i64, modulus as i64))
+}
+
+/// Largest integer whose square is at most `n`
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method from above converges to the floor of the root
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+
+    loop {
+        let next = (x + n / x) / 2;
+
+        if next >= x {
+            return x;
+        }
+
+        x = next;
+    }
+}
+
+/// Whether `n` is a perfect square, and its root if so
+pub fn exact_sqrt(n: u128) -> Option<u128> {
+    let root = isqrt(n);
+    (root * root == n).then_some(root)
+}
+
+/// The integers right around a real root: `floor <= root <= ceil`.
+/// Both are equal when the root is an integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootBracket {
+    pub floor: i128,
+    pub ceil: i128,
+}
+
+impl RootBracket {
+    pub fn is_exact(&self) -> bool {
+        self.floor == self.ceil
+    }
+}
+
+/// Brackets the real roots of `a x² + b x + c`, smallest first, without any
+/// floating point rounding. `None` if `a` is 0, there are no real roots, or
+/// the discriminant doesn't fit in an `i128`
+pub fn quadratic_roots(a: i64, b: i64, c: i64) -> Option<[RootBracket; 2]> {
+    if a == 0 {
+        return None;
+    }
+
+    // Same roots with a positive leading coefficient
+    let sign = a.signum() as i128;
+    let (a, b, c) = (a as i128 * sign, b as i128 * sign, c as i128 * sign);
+
+    let discriminant = (b * b).checked_sub(a.checked_mul(c)?.checked_mul(4)?)?;
+    if discriminant < 0 {
+        return None;
+    }
+
+    let root_floor = isqrt(discriminant as u128) as i128;
+    let root_ceil = if root_floor * root_floor == discriminant {
+        root_floor
+    } else {
+        root_floor + 1
+    };
+
+    // floor(x / q) == floor(floor(x) / q) for any real x and q > 0, and the
+    // same goes for ceil
+    let q = 2 * a;
+    let floor_div = |x: i128| x.div_euclid(q);
+    let ceil_div = |x: i128| -(-x).div_euclid(q);
+
+    Some([
+        RootBracket {
+            floor: floor_div(-b - root_ceil),
+            ceil: ceil_div(-b - root_floor),
+        },
+        RootBracket {
+            floor: floor_div(-b + root_floor),
+            ceil: ceil_div(-b + root_ceil),
+        },
+    ])
+}
+
+/// Differences between consecutive values, `None` if one doesn't fit in an
+/// `i64`
+pub fn differences(values: &[i64]) -> Option<Vec<i64>> {
+    values
+        .windows(2)
+        .map(|window| window[1].checked_sub(window[0]))
+        .collect()
+}
+
+/// The values followed by their successive differences, down to the first
+/// row where all of them are 0
+pub fn difference_table(values: &[i64]) -> Option<Vec<Vec<i64>>> {
+    let mut table = vec![values.to_vec()];
+
+    while let Some(last) = table.last() {
+        if last.iter().all(|&value| value == 0) {
+            break;
+        }
+
+        table.push(differences(last)?);
+    }
+
+    Some(table)
+}
+
+/// Next value of the sequence, assuming its differences eventually become
+/// constant. 0 for an empty sequence
+pub fn extrapolate_forward(values: &[i64]) -> Option<i64> {
+    difference_table(values)?
+        .iter()
+        .filter_map(|row| row.last())
+        .try_fold(0i64, |sum, &last| sum.checked_add(last))
+}
+
+/// Value that would come before the first one of the sequence
+pub fn extrapolate_backward(values: &[i64]) -> Option<i64> {
+    difference_table(values)?
+        .iter()
+        .filter_map(|row| row.first())
+        .rev()
+        .try_fold(0i64, |previous, &first| first.checked_sub(previous))
+}
@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Parses a single token, reporting `message` if it is not valid
+pub fn parse_token<T: FromStr>(line: &str, token: &str, message: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at_token(format!("{message} `{token}`"), line, token))
+}
+
+/// Parses a whitespace separated list of numbers like "79 14 55 13"
+pub fn parse_numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace()
+        .map(|number| parse_token(s, number, "invalid number"))
+        .collect()
+}
+
+/// Parses every non empty line of the input, tagging errors with the line
+/// they were found in
+pub fn parse_lines<T>(contents: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_with(contents, T::from_str)
+}
+
+/// Same as `parse_lines`, using `parse` to parse every line
+pub fn parse_lines_with<T, F>(contents: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    crate::numbered_lines(contents)
+        .into_iter()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(number, line)| {
+            parse(line).map_err(|error| error.at_line(number).with_source_line(line))
+        })
+        .collect()
+}
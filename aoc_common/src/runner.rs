@@ -0,0 +1,278 @@
+use std::fmt::Display;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::{log, ParseError, Solution};
+
+/// The answer to one part of a puzzle and how long it took to compute
+#[derive(Debug, Clone)]
+pub struct PartAnswer {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+impl PartAnswer {
+    /// Formats the answer as a `{day, input, part, answer, elapsed_ns}` JSON
+    /// object. Integer answers are written as numbers
+    pub fn to_json(&self, day: u8, filename: &str) -> String {
+        format!(
+            "{{\"day\":{},\"input\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{}}}",
+            day,
+            crate::json::quote(crate::input_name(filename)),
+            self.part,
+            crate::json::value(&self.answer),
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+/// How answers are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Human readable text
+    #[default]
+    Text,
+    /// One JSON object per answer and line
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {s}")),
+        }
+    }
+}
+
+/// Parses the input and solves the requested parts (1 and/or 2) in order
+pub fn solve<S: Solution>(contents: &str, parts: &[u8]) -> Result<Vec<PartAnswer>, ParseError> {
+    solve_with(contents, parts, S::parse, S::part1, S::part2)
+}
+
+/// Like `solve`, with the parser and parts given as functions, for days
+/// whose answers depend on command line options
+pub fn solve_with<I, A: Display>(
+    contents: &str,
+    parts: &[u8],
+    parse: impl Fn(&str) -> Result<I, ParseError>,
+    part1: impl Fn(&I) -> Result<A, ParseError>,
+    part2: impl Fn(&I) -> Result<A, ParseError>,
+) -> Result<Vec<PartAnswer>, ParseError> {
+    let input = parse(contents)?;
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => part1(&input)?.to_string(),
+                2 => part2(&input)?.to_string(),
+                _ => panic!("Invalid part: {part}"),
+            };
+
+            Ok(PartAnswer {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect()
+}
+
+/// Reads an input file, exiting with an error message if it can't be read
+pub fn read_input_or_exit(filename: &str) -> String {
+    crate::read_input(filename).unwrap_or_else(|error| {
+        eprintln!("{}", read_error(filename, error));
+        process::exit(1);
+    })
+}
+
+/// Prints a parse error found in `filename` and exits
+pub fn exit_with_parse_error(error: ParseError, filename: &str) -> ! {
+    eprintln!(
+        "{}",
+        error.in_file(crate::input_name(filename)).diagnostic()
+    );
+    process::exit(1);
+}
+
+fn read_error(filename: &str, error: std::io::Error) -> String {
+    format!(
+        "error: could not read `{}`: {error}",
+        crate::input_name(filename)
+    )
+}
+
+/// Reads and parses an input file. Errors are returned ready to be
+/// printed, so that other files can still be read
+pub fn parse_file<I>(
+    parse: impl Fn(&str) -> Result<I, ParseError>,
+    filename: &str,
+) -> Result<I, String> {
+    let contents = crate::read_input(filename).map_err(|error| read_error(filename, error))?;
+
+    parse(&contents).map_err(|error| error.in_file(crate::input_name(filename)).diagnostic())
+}
+
+/// Reads and solves the given parts of an input file. Errors are returned
+/// ready to be printed, so that other files can still be solved
+pub fn solve_file(
+    solve: impl Fn(&str, &[u8]) -> Result<Vec<PartAnswer>, ParseError>,
+    filename: &str,
+    parts: &[u8],
+) -> Result<Vec<PartAnswer>, String> {
+    parse_file(|contents| solve(contents, parts), filename)
+}
+
+/// The value following `flag`
+pub fn flag_value(flag: &str, args: &mut dyn Iterator<Item = String>) -> Result<String, String> {
+    args.next().ok_or(format!("Missing value for {flag}"))
+}
+
+const USAGE: &str = "Usage: day_N [-v|-vv] [--format <text|json>] [options] [input...]
+
+Use `-` as input to read from stdin";
+
+/// Command line options understood by every day binary
+#[derive(Debug, Clone, Default)]
+pub struct Cli {
+    pub format: Format,
+    pub filenames: Vec<String>,
+}
+
+impl Cli {
+    /// Parses the arguments, without the program name. Flags other than the
+    /// common ones are handed to `day_option` along with the remaining
+    /// arguments, so it can take their values. It returns whether it knew
+    /// the flag
+    pub fn parse<F>(
+        args: impl IntoIterator<Item = String>,
+        mut day_option: F,
+    ) -> Result<Cli, String>
+    where
+        F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>,
+    {
+        let mut cli = Cli::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if let Some(verbosity) = log::verbosity_flag(&arg) {
+                log::set_verbosity(log::verbosity() + verbosity);
+                continue;
+            }
+
+            match arg.as_str() {
+                "--format" => cli.format = flag_value(&arg, &mut args)?.parse()?,
+                _ if arg.starts_with("--") => {
+                    if !day_option(&arg, &mut args)? {
+                        return Err(format!("Unexpected argument: {arg}"));
+                    }
+                }
+                _ => cli.filenames.push(arg),
+            }
+        }
+
+        Ok(cli)
+    }
+
+    /// Parses the arguments of the process, exiting with the usage and the
+    /// day's `options` if they are wrong
+    pub fn from_env<F>(options: &str, day_option: F) -> Cli
+    where
+        F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>,
+    {
+        Cli::parse(std::env::args().skip(1), day_option).unwrap_or_else(|error| {
+            eprintln!("{error}\n\n{USAGE}");
+
+            if !options.is_empty() {
+                eprintln!("\nOptions:\n{options}");
+            }
+
+            process::exit(2);
+        })
+    }
+
+    /// The files given, or `default` when there are none
+    pub fn inputs<'a>(&'a self, default: &'a str) -> Vec<&'a str> {
+        inputs(&self.filenames, default)
+    }
+
+    /// Solves both parts for every file given, or `default_input`, and
+    /// prints the answers. Exits if any of the files couldn't be solved
+    pub fn run(
+        &self,
+        day: u8,
+        default_input: &str,
+        solve: impl Fn(&str, &[u8]) -> Result<Vec<PartAnswer>, ParseError>,
+    ) {
+        let solved = for_each_input(
+            &self.inputs(default_input),
+            self.format,
+            |filename| solve_file(&solve, filename, &[1, 2]),
+            |filename, answers| {
+                for answer in answers {
+                    match self.format {
+                        Format::Text => println!("Part {}: {}", answer.part, answer.answer),
+                        Format::Json => println!("{}", answer.to_json(day, filename)),
+                    }
+                }
+            },
+        );
+
+        if !solved {
+            process::exit(1);
+        }
+    }
+}
+
+/// The given files, or `default` when there are none
+pub fn inputs<'a>(filenames: &'a [String], default: &'a str) -> Vec<&'a str> {
+    if filenames.is_empty() {
+        return vec![default];
+    }
+
+    filenames.iter().map(String::as_str).collect()
+}
+
+/// Hands what `solve` makes of every file to `print`. In text format, the
+/// output of each file is labelled with its name when there is more than
+/// one. Errors are printed and the remaining files still solved. Returns
+/// whether all of them could be
+pub fn for_each_input<T>(
+    filenames: &[&str],
+    format: Format,
+    mut solve: impl FnMut(&str) -> Result<T, String>,
+    mut print: impl FnMut(&str, T),
+) -> bool {
+    let mut solved = true;
+
+    for filename in filenames {
+        match solve(filename) {
+            Ok(output) => {
+                if format == Format::Text && filenames.len() > 1 {
+                    println!("{}:", crate::input_name(filename));
+                }
+
+                print(filename, output);
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                solved = false;
+            }
+        }
+    }
+
+    solved
+}
+
+/// Entry point shared by the day binaries: solves both parts for every file
+/// given as argument, or the day's default input
+pub fn main<S: Solution>() {
+    Cli::from_env("", |_, _| Ok(false)).run(S::DAY, S::DEFAULT_INPUT, solve::<S>);
+}
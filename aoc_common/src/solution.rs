@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// A day of the advent calendar: how to parse its input and how to answer
+/// both parts of the puzzle from the parsed input. A part fails when the
+/// input parses but has no answer, like a path that never ends.
+pub trait Solution {
+    /// Day of the month the puzzle was released on
+    const DAY: u8;
+    /// Puzzle input used when no file name is given
+    const DEFAULT_INPUT: &'static str;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer, ParseError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer, ParseError>;
+}
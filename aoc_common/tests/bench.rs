@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use aoc_common::bench::{self, BenchRecord, Timings};
+
+fn record(day: u8, phase: &str, median: u64) -> BenchRecord {
+    record_on(day, "input.txt", phase, median)
+}
+
+fn record_on(day: u8, input: &str, phase: &str, median: u64) -> BenchRecord {
+    BenchRecord {
+        day,
+        input: Some(input.to_string()),
+        phase: phase.to_string(),
+        min: Duration::from_nanos(median / 2),
+        median: Duration::from_nanos(median),
+        max: Duration::from_nanos(median * 2),
+        runs: 10,
+    }
+}
+
+#[test]
+fn timings_summary() {
+    let timings = Timings {
+        phase: "part1".to_string(),
+        samples: [30, 10, 20, 50, 40].map(Duration::from_nanos).to_vec(),
+    };
+
+    assert_eq!(timings.min(), Duration::from_nanos(10));
+    assert_eq!(timings.median(), Duration::from_nanos(30));
+    assert_eq!(timings.max(), Duration::from_nanos(50));
+}
+
+#[test]
+fn records_round_trip() {
+    let records = vec![
+        record(5, "parse", 100),
+        record(5, "part2", 4000),
+        record_on(5, "my inputs/day 5.txt", "part1", 300),
+    ];
+    let contents: String = records.iter().map(|r| format!("{r}\n")).collect();
+
+    let parsed = bench::parse_records(&format!("# header\n{contents}")).unwrap();
+
+    assert_eq!(parsed, records);
+}
+
+#[test]
+fn invalid_record_points_at_the_line() {
+    let error = bench::parse_records("# header\n5 parse 1 2 x 10 input.txt\n").unwrap_err();
+
+    assert_eq!(error.line, Some(2));
+    assert_eq!(error.token, "x");
+}
+
+#[test]
+fn merge_replaces_rerun_phases_only() {
+    let previous = vec![
+        record(3, "part1", 10),
+        record(5, "part1", 10),
+        record(5, "part2", 10),
+    ];
+    let new = vec![record(5, "part1", 20)];
+
+    let merged = bench::merge_records(&previous, &new);
+
+    assert_eq!(
+        merged,
+        vec![
+            record(3, "part1", 10),
+            record(5, "part1", 20),
+            record(5, "part2", 10)
+        ]
+    );
+}
+
+#[test]
+fn compare_flags_regressions() {
+    let previous = vec![record(1, "part1", 100), record(1, "part2", 100)];
+    let new = vec![
+        record(1, "part1", 105),
+        record(1, "part2", 150),
+        record(1, "parse", 10),
+    ];
+
+    let comparisons = bench::compare(&new, &previous, 10.0);
+
+    assert!(!comparisons[0].regression);
+    assert!(comparisons[1].regression);
+    assert_eq!(comparisons[1].change(), Some(50.0));
+    assert_eq!(comparisons[2].previous, None);
+    assert!(!comparisons[2].regression);
+}
+
+#[test]
+fn records_without_input_are_kept_apart() {
+    // As saved before the input was recorded
+    let previous = bench::parse_records("# header\n5 part1 5 10 20 10\n").unwrap();
+    assert_eq!(previous[0].input, None);
+    assert_eq!(previous[0].to_string(), "5 part1 5 10 20 10");
+
+    // Never compared with nor replaced by new records
+    let new = vec![record(5, "part1", 100)];
+    let comparisons = bench::compare(&new, &previous, 10.0);
+    assert_eq!(comparisons[0].previous, None);
+    assert!(!comparisons[0].regression);
+
+    let merged = bench::merge_records(&previous, &new);
+    assert_eq!(merged, vec![previous[0].clone(), new[0].clone()]);
+}
+
+#[test]
+fn other_inputs_are_kept_apart() {
+    let previous = vec![
+        record_on(5, "day_5/input.txt", "part1", 1000),
+        record_on(5, "day_5/test.txt", "part1", 10),
+    ];
+    let new = vec![record_on(5, "day_5/input.txt", "part1", 1100)];
+
+    let merged = bench::merge_records(&previous, &new);
+    assert_eq!(merged, vec![new[0].clone(), previous[1].clone()]);
+
+    // Compared with the same input, not with the much faster test one
+    let comparisons = bench::compare(&new, &previous, 20.0);
+    assert_eq!(comparisons[0].previous, Some(previous[0].median));
+    assert!(!comparisons[0].regression);
+
+    let elsewhere = vec![record_on(5, "other.txt", "part1", 1100)];
+    assert_eq!(
+        bench::compare(&elsewhere, &previous, 20.0)[0].previous,
+        None
+    );
+}
@@ -0,0 +1,120 @@
+use aoc_common::ParseError;
+
+#[test]
+fn caret_under_the_token() {
+    let line = "Game 3: x blue";
+    let error = ParseError::at_token("invalid cube count", line, &line[8..9])
+        .at_line(3)
+        .in_file("day_2/input.txt");
+
+    assert_eq!(
+        error.diagnostic(),
+        "\
+error: invalid cube count
+ --> day_2/input.txt:3:9
+  |
+3 | Game 3: x blue
+  |         ^"
+    );
+    assert_eq!(error.to_string(), "day_2/input.txt:3:9: invalid cube count");
+}
+
+#[test]
+fn carets_span_the_token() {
+    let line = "seeds: 79 14x 55";
+    let error = ParseError::at_token("invalid number", line, &line[10..13]).at_line(1);
+
+    assert_eq!(
+        error.diagnostic(),
+        "\
+error: invalid number
+ --> <input>:1:11
+  |
+1 | seeds: 79 14x 55
+  |           ^^^"
+    );
+}
+
+#[test]
+fn gutter_fits_the_line_number() {
+    let error = ParseError::at_token("invalid direction `X`", "LRX", "X")
+        .at_line(1042)
+        .in_file("day_8/input");
+
+    assert_eq!(
+        error.diagnostic(),
+        "\
+error: invalid direction `X`
+    --> day_8/input:1042:3
+     |
+1042 | LRX
+     |   ^"
+    );
+}
+
+#[test]
+fn columns_count_characters() {
+    // `é` and `ü` are two bytes each
+    let line = "é ü x";
+    let error = ParseError::at_token("unexpected `x`", line, &line[6..]).at_line(2);
+
+    assert_eq!(error.column, 6);
+    assert_eq!(
+        error.diagnostic(),
+        "\
+error: unexpected `x`
+ --> <input>:2:5
+  |
+2 | é ü x
+  |     ^"
+    );
+
+    // A multibyte token gets one caret per character
+    let error = ParseError::at_token("invalid card `ü`", line, &line[3..5]).at_line(1);
+    assert!(error.diagnostic().ends_with("\n  |   ^"));
+}
+
+#[test]
+fn missing_token_at_the_end_of_the_line() {
+    let error = ParseError::at_end("expected `:`", "Game 1").at_line(1);
+
+    assert_eq!(
+        error.diagnostic(),
+        "\
+error: expected `:`
+ --> <input>:1:7
+  |
+1 | Game 1
+  |       ^"
+    );
+}
+
+#[test]
+fn errors_within_part_of_a_line() {
+    let line = "AAA = (BBB CCC)";
+    let value = &line[6..];
+    let error = ParseError::at_token("expected `,`", value, &value[4..])
+        .within(line, value)
+        .at_line(5);
+
+    assert_eq!(error.column, 10);
+    assert_eq!(error.source_line.as_deref(), Some(line));
+    assert!(error.diagnostic().ends_with("\n  |           ^^^^^"));
+}
+
+#[test]
+fn errors_without_a_line() {
+    let error = ParseError::new("no node named `AAA`", "AAA", 0);
+    assert_eq!(error.diagnostic(), "error: no node named `AAA`");
+    assert_eq!(error.to_string(), "no node named `AAA`");
+
+    let error = error.in_file("day_8/example_3.txt");
+    assert_eq!(
+        error.diagnostic(),
+        "error: no node named `AAA`\n --> day_8/example_3.txt"
+    );
+    assert_eq!(
+        error.to_string(),
+        "day_8/example_3.txt: no node named `AAA`"
+    );
+}
@@ -0,0 +1,84 @@
+use aoc_common::grid::Grid;
+use aoc_common::ParseError;
+
+fn sample() -> Grid<char> {
+    Grid::parse_chars("abc\ndef\n").unwrap()
+}
+
+#[test]
+fn access_by_row_and_column() {
+    let grid = sample();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 2)], 'f');
+    assert_eq!(grid.get((0, 1)), Some(&'b'));
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.get((0, 3)), None);
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = sample();
+
+    let corner: Vec<_> = grid.neighbours8((0, 0)).collect();
+    assert_eq!(corner, vec![(0, 1), (1, 0), (1, 1)]);
+
+    let middle: Vec<_> = grid.neighbours4((0, 1)).collect();
+    assert_eq!(middle, vec![(0, 0), (0, 2), (1, 1)]);
+
+    assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = sample();
+
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    assert_eq!(rows, vec!["abc", "def"]);
+
+    let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    assert_eq!(columns, vec!["ad", "be", "cf"]);
+
+    assert_eq!(grid.row(2), None);
+    assert_eq!(grid.column(3).count(), 0);
+}
+
+#[test]
+fn transpose_swaps_rows_and_columns() {
+    let transposed = sample().transpose();
+
+    assert_eq!((transposed.width(), transposed.height()), (2, 3));
+    assert_eq!(transposed.to_string(), "ad\nbe\ncf\n");
+    assert_eq!(transposed.transpose(), sample());
+}
+
+#[test]
+fn pretty_print_pads_cells() {
+    let grid = Grid::from_rows(vec![vec![1, 20], vec![300, 4]]).unwrap();
+
+    assert_eq!(format!("{grid:4}"), "   1  20\n 300   4\n");
+}
+
+#[test]
+fn ragged_rows_are_rejected() {
+    let error = Grid::parse_chars("abc\nde\n").unwrap_err();
+    assert_eq!(error.line, Some(2));
+
+    let error = Grid::from_rows(vec![vec![1, 2], vec![3]]).unwrap_err();
+    assert_eq!(error.line, Some(2));
+}
+
+#[test]
+fn cell_errors_point_at_the_cell() {
+    let digit = |c: char| {
+        c.to_digit(10)
+            .ok_or_else(|| ParseError::new("not a digit", c.to_string(), 0))
+    };
+
+    let grid = Grid::parse_with("12\n34\n", digit).unwrap();
+    assert_eq!(grid.map(|cell| cell * 2)[(1, 1)], 8);
+
+    let error = Grid::parse_with("12\n3x\n", digit).unwrap_err();
+    assert_eq!((error.line, error.column), (Some(2), 1));
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use aoc_common::json;
+use aoc_common::runner::{Format, PartAnswer};
+
+#[test]
+fn quote_escapes_special_characters() {
+    assert_eq!(json::quote("plain"), r#""plain""#);
+    assert_eq!(json::quote("a \"b\"\\c\n"), r#""a \"b\"\\c\n""#);
+    assert_eq!(json::quote("\u{1}"), r#""\u0001""#);
+}
+
+#[test]
+fn integer_answers_are_numbers() {
+    assert_eq!(json::value("-42"), "-42");
+    assert_eq!(json::value("12634632"), "12634632");
+    assert_eq!(json::value("ABC"), r#""ABC""#);
+}
+
+#[test]
+fn answer_record() {
+    let answer = PartAnswer {
+        part: 2,
+        answer: "114".to_string(),
+        elapsed: Duration::from_nanos(1500),
+    };
+
+    assert_eq!(
+        answer.to_json(9, "day_9/test.txt"),
+        r#"{"day":9,"input":"day_9/test.txt","part":2,"answer":114,"elapsed_ns":1500}"#
+    );
+}
+
+#[test]
+fn format_from_str() {
+    assert_eq!("json".parse(), Ok(Format::Json));
+    assert_eq!("text".parse(), Ok(Format::Text));
+    assert!("yaml".parse::<Format>().is_err());
+}
+
+#[test]
+fn stdin_is_named_in_records() {
+    let answer = PartAnswer {
+        part: 1,
+        answer: "-3".to_string(),
+        elapsed: Duration::ZERO,
+    };
+
+    assert_eq!(
+        answer.to_json(9, "-"),
+        r#"{"day":9,"input":"<stdin>","part":1,"answer":-3,"elapsed_ns":0}"#
+    );
+}
@@ -0,0 +1,25 @@
+use aoc_common::log::{self, Level};
+
+#[test]
+fn verbosity_flags() {
+    assert_eq!(log::verbosity_flag("-v"), Some(1));
+    assert_eq!(log::verbosity_flag("-vv"), Some(2));
+    assert_eq!(log::verbosity_flag("-"), None);
+    assert_eq!(log::verbosity_flag("-x"), None);
+    assert_eq!(log::verbosity_flag("--verbose"), None);
+    assert_eq!(log::verbosity_flag("input.txt"), None);
+}
+
+#[test]
+fn levels_follow_verbosity() {
+    assert!(!log::enabled(Level::Debug));
+
+    log::set_verbosity(1);
+    assert!(log::enabled(Level::Debug));
+    assert!(!log::enabled(Level::Trace));
+
+    log::set_verbosity(2);
+    assert!(log::enabled(Level::Trace));
+
+    log::set_verbosity(0);
+}
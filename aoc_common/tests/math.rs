@@ -0,0 +1,141 @@
+use aoc_common::math::{self, RootBracket};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(math::gcd(12, 18), 6);
+    assert_eq!(math::gcd(0, 7), 7);
+    assert_eq!(math::gcd(0, 0), 0);
+
+    assert_eq!(math::checked_lcm(4, 6), Some(12));
+    assert_eq!(math::checked_lcm(0, 6), Some(0));
+    assert_eq!(math::checked_lcm(u64::MAX, u64::MAX - 1), None);
+
+    assert_eq!(math::checked_lcm_all([2, 3, 4]), Some(12));
+    assert_eq!(math::checked_lcm_all([]), Some(1));
+}
+
+#[test]
+fn extended_gcd_finds_bezout_coefficients() {
+    for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (i64::MAX, 2)] {
+        let (g, x, y) = math::extended_gcd(a, b).unwrap();
+
+        assert_eq!(g, math::gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+        assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+    }
+
+    assert_eq!(math::extended_gcd(i64::MIN, 0), None);
+}
+
+#[test]
+fn chinese_remainder_theorem() {
+    assert_eq!(math::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(math::crt(&[(-1, 4), (3, 6)]), Some((3, 12)));
+    assert_eq!(math::crt(&[]), Some((0, 1)));
+
+    // x ≡ 1 (mod 4) and x ≡ 2 (mod 6) can't both hold
+    assert_eq!(math::crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(math::crt(&[(1, 0)]), None);
+    assert_eq!(math::crt(&[(0, i64::MAX), (0, i64::MAX - 1)]), None);
+}
+
+#[test]
+fn integer_square_roots() {
+    assert_eq!(math::isqrt(0), 0);
+    assert_eq!(math::isqrt(15), 3);
+    assert_eq!(math::isqrt(16), 4);
+    assert_eq!(math::isqrt(u128::MAX), u64::MAX as u128);
+
+    let big = (1u128 << 60) + 3;
+    assert_eq!(math::isqrt(big * big - 1), big - 1);
+    assert_eq!(math::exact_sqrt(big * big), Some(big));
+    assert_eq!(math::exact_sqrt(big * big + 1), None);
+}
+
+#[test]
+fn quadratic_roots_are_bracketed() {
+    let bracket = |floor, ceil| RootBracket { floor, ceil };
+
+    // (x - 2)(x - 5)
+    assert_eq!(
+        math::quadratic_roots(1, -7, 10),
+        Some([bracket(2, 2), bracket(5, 5)])
+    );
+
+    // 7 ms race with a 9 mm record: roots at 1.69 and 5.30
+    assert_eq!(
+        math::quadratic_roots(1, -7, 9),
+        Some([bracket(1, 2), bracket(5, 6)])
+    );
+
+    // Same roots when the parabola opens downwards
+    assert_eq!(
+        math::quadratic_roots(-1, 7, -9),
+        math::quadratic_roots(1, -7, 9)
+    );
+
+    // Negative roots: -3.41 and -0.58
+    assert_eq!(
+        math::quadratic_roots(1, 4, 2),
+        Some([bracket(-4, -3), bracket(-1, 0)])
+    );
+
+    assert_eq!(math::quadratic_roots(1, 0, 1), None);
+    assert_eq!(math::quadratic_roots(0, 1, 1), None);
+}
+
+#[test]
+fn finite_differences() {
+    assert_eq!(math::differences(&[1, 3, 6, 10]), Some(vec![2, 3, 4]));
+    assert_eq!(
+        math::difference_table(&[1, 3, 6, 10]),
+        Some(vec![vec![1, 3, 6, 10], vec![2, 3, 4], vec![1, 1], vec![0]])
+    );
+
+    assert_eq!(
+        math::extrapolate_forward(&[10, 13, 16, 21, 30, 45]),
+        Some(68)
+    );
+    assert_eq!(
+        math::extrapolate_backward(&[10, 13, 16, 21, 30, 45]),
+        Some(5)
+    );
+    assert_eq!(math::extrapolate_forward(&[]), Some(0));
+    assert_eq!(math::extrapolate_forward(&[7]), Some(7));
+}
+
+#[test]
+fn finite_differences_overflow() {
+    assert_eq!(math::differences(&[i64::MIN, i64::MAX]), None);
+    assert_eq!(math::difference_table(&[i64::MIN, i64::MAX]), None);
+    assert_eq!(math::extrapolate_forward(&[i64::MIN, i64::MAX]), None);
+    assert_eq!(math::extrapolate_backward(&[i64::MAX, i64::MIN]), None);
+
+    // The differences fit, the extrapolated value doesn't
+    assert_eq!(math::extrapolate_forward(&[i64::MAX - 1, i64::MAX]), None);
+    assert_eq!(math::extrapolate_backward(&[i64::MIN, i64::MIN + 1]), None);
+    assert_eq!(
+        math::extrapolate_backward(&[i64::MIN + 1, i64::MIN + 2]),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        math::extrapolate_forward(&[i64::MAX - 2, i64::MAX - 1]),
+        Some(i64::MAX)
+    );
+}
+
+#[test]
+fn quadratic_roots_overflow() {
+    assert_eq!(math::quadratic_roots(i64::MAX, 0, i64::MAX), None);
+    assert_eq!(math::quadratic_roots(i64::MAX, 0, i64::MIN), None);
+
+    // Large but fitting coefficients: x² - (2³² + 1) x + 2³² = (x - 1)(x - 2³²)
+    let [low, high] = math::quadratic_roots(1, -(1 << 32) - 1, 1 << 32).unwrap();
+    assert_eq!(low, RootBracket { floor: 1, ceil: 1 });
+    assert_eq!(
+        high,
+        RootBracket {
+            floor: 1 << 32,
+            ceil: 1 << 32
+        }
+    );
+}
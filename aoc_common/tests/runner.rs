@@ -0,0 +1,22 @@
+use aoc_common::runner::{Cli, Format};
+
+fn cli(args: &[&str]) -> Cli {
+    Cli::parse(args.iter().map(|arg| arg.to_string()), |_, _| Ok(false)).unwrap()
+}
+
+#[test]
+fn inputs_default_when_none_are_given() {
+    assert_eq!(cli(&[]).inputs("input.txt"), ["input.txt"]);
+    assert_eq!(
+        cli(&["--format", "json"]).inputs("input.txt"),
+        ["input.txt"]
+    );
+}
+
+#[test]
+fn inputs_keep_the_order_they_were_given_in() {
+    let cli = cli(&["b.txt", "-", "a.txt"]);
+
+    assert_eq!(cli.inputs("input.txt"), ["b.txt", "-", "a.txt"]);
+    assert_eq!(cli.format, Format::Text);
+}
@@ -0,0 +1,312 @@
+//! Numbers written as english cardinals, like "forty-two" or "one hundred
+//! and three", found among the words of a line.
+//!
+//! Words are separated by spaces or hyphens, but can also be glued to each
+//! other or to other letters, like in "xtwone3four" or "fortytwo". A run of
+//! letters is read from the left, taking the longest word of a number at
+//! each step, so words don't overlap: "eightwo" is eight followed by the
+//! letters "wo", which break numbers apart. A run of digits like "42" is a
+//! single number.
+
+use std::sync::OnceLock;
+
+use crate::{Match, Scanner};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Word {
+    /// zero to nine
+    Unit(usize),
+    /// ten to nineteen
+    Teen(usize),
+    /// twenty, thirty, ... ninety
+    Tens(usize),
+    Hundred,
+    /// thousand, million, billion
+    Scale(usize),
+    And,
+    Digits(usize),
+    /// Any other word or punctuation, which ends a number
+    Break,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    word: Word,
+    start: usize,
+    end: usize,
+}
+
+const UNITS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// From twenty on, by value / 10
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [(&str, usize); 3] = [
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("billion", 1_000_000_000),
+];
+
+fn position(words: &[&str], word: &str) -> Option<usize> {
+    words
+        .iter()
+        .position(|candidate| !candidate.is_empty() && candidate.eq_ignore_ascii_case(word))
+}
+
+fn classify(word: &str) -> Word {
+    if word.bytes().all(|byte| byte.is_ascii_digit()) {
+        return word.parse().map_or(Word::Break, Word::Digits);
+    }
+
+    if let Some(value) = position(&UNITS, word) {
+        return Word::Unit(value);
+    }
+
+    if let Some(value) = position(&TEENS, word) {
+        return Word::Teen(10 + value);
+    }
+
+    if let Some(value) = position(&TENS, word) {
+        return Word::Tens(value * 10);
+    }
+
+    if word.eq_ignore_ascii_case("hundred") {
+        return Word::Hundred;
+    }
+
+    if word.eq_ignore_ascii_case("and") {
+        return Word::And;
+    }
+
+    SCALES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(word))
+        .map_or(Word::Break, |&(_, scale)| Word::Scale(scale))
+}
+
+/// Every word numbers are made of
+fn vocabulary() -> &'static Scanner {
+    static VOCABULARY: OnceLock<Scanner> = OnceLock::new();
+
+    VOCABULARY.get_or_init(|| {
+        let words = UNITS
+            .iter()
+            .chain(&TEENS)
+            .chain(&TENS)
+            .copied()
+            .chain(["hundred", "and"])
+            .chain(SCALES.map(|(name, _)| name))
+            .filter(|word| !word.is_empty());
+
+        Scanner::new(words.map(|word| (word, 0)))
+    })
+}
+
+/// Splits the run of letters of the line between `start` and `end` into
+/// the words of numbers in it, like "xtwone" into "x", "two" and "ne". The
+/// letters around those words break numbers apart
+fn split_letters(line: &str, start: usize, end: usize, tokens: &mut Vec<Token>) {
+    let letters = line[start..end].to_ascii_lowercase();
+    let mut offset = 0;
+
+    while offset < letters.len() {
+        let Some(found) = vocabulary().find_first(&letters[offset..]) else {
+            break;
+        };
+
+        if found.start > 0 {
+            tokens.push(Token {
+                word: Word::Break,
+                start: start + offset,
+                end: start + offset + found.start,
+            });
+        }
+
+        tokens.push(Token {
+            word: classify(&letters[offset + found.start..offset + found.end]),
+            start: start + offset + found.start,
+            end: start + offset + found.end,
+        });
+
+        offset += found.end;
+    }
+
+    if offset < letters.len() {
+        tokens.push(Token {
+            word: Word::Break,
+            start: start + offset,
+            end,
+        });
+    }
+}
+
+/// Splits the line into the words of numbers and runs of digits. Spaces and
+/// hyphens only separate words, anything else breaks numbers apart
+fn tokenize(line: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let bytes = line.as_bytes();
+    let mut start = 0;
+
+    while start < bytes.len() {
+        let byte = bytes[start];
+
+        let same_kind: fn(&u8) -> bool = if byte.is_ascii_alphabetic() {
+            u8::is_ascii_alphabetic
+        } else if byte.is_ascii_digit() {
+            u8::is_ascii_digit
+        } else {
+            if byte != b' ' && byte != b'-' {
+                tokens.push(Token {
+                    word: Word::Break,
+                    start,
+                    end: start + 1,
+                });
+            }
+
+            start += 1;
+            continue;
+        };
+
+        let length = bytes[start..].iter().take_while(|b| same_kind(b)).count();
+        let end = start + length;
+
+        if byte.is_ascii_alphabetic() {
+            split_letters(line, start, end, &mut tokens);
+        } else {
+            tokens.push(Token {
+                word: classify(&line[start..end]),
+                start,
+                end,
+            });
+        }
+
+        start = end;
+    }
+
+    tokens
+}
+
+fn word_at(tokens: &[Token], index: usize) -> Option<Word> {
+    tokens.get(index).map(|token| token.word)
+}
+
+/// 1 to 99: "seven", "twelve", "forty", "forty-two"
+fn below_hundred(tokens: &[Token], index: usize) -> Option<(usize, usize)> {
+    match word_at(tokens, index)? {
+        Word::Unit(unit) if unit > 0 => Some((unit, index + 1)),
+        Word::Teen(teen) => Some((teen, index + 1)),
+        Word::Tens(tens) => match word_at(tokens, index + 1) {
+            Some(Word::Unit(unit)) if unit > 0 => Some((tens + unit, index + 2)),
+            _ => Some((tens, index + 1)),
+        },
+        _ => None,
+    }
+}
+
+/// Skips an "and" if what follows it continues the number
+fn skip_and(tokens: &[Token], index: usize) -> usize {
+    match word_at(tokens, index) {
+        Some(Word::And) if below_hundred(tokens, index + 1).is_some() => index + 1,
+        _ => index,
+    }
+}
+
+/// 1 to 999: below a hundred, or "three hundred" followed by an optional
+/// "and" and something below a hundred
+fn below_thousand(tokens: &[Token], index: usize) -> Option<(usize, usize)> {
+    let (value, next) = below_hundred(tokens, index)?;
+
+    if value >= 10 || word_at(tokens, next) != Some(Word::Hundred) {
+        return Some((value, next));
+    }
+
+    let hundreds = value * 100;
+    let after_and = skip_and(tokens, next + 1);
+
+    match below_hundred(tokens, after_and) {
+        Some((rest, end)) => Some((hundreds + rest, end)),
+        None => Some((hundreds, next + 1)),
+    }
+}
+
+/// A whole number starting at `index`, and the index of the token after it
+fn number(tokens: &[Token], index: usize) -> Option<(usize, usize)> {
+    match word_at(tokens, index)? {
+        Word::Unit(0) => return Some((0, index + 1)),
+        Word::Digits(value) => return Some((value, index + 1)),
+        _ => {}
+    }
+
+    let (mut group, mut next) = below_thousand(tokens, index)?;
+    let mut total: usize = 0;
+    let mut last_scale = usize::MAX;
+
+    // Scales must get smaller: "two million three thousand"
+    while let Some(Word::Scale(scale)) = word_at(tokens, next) {
+        if scale >= last_scale {
+            break;
+        }
+
+        total = total.checked_add(group.checked_mul(scale)?)?;
+        last_scale = scale;
+        next += 1;
+        group = 0;
+
+        let after_and = skip_and(tokens, next);
+
+        if let Some((value, end)) = below_thousand(tokens, after_and) {
+            group = value;
+            next = end;
+        }
+    }
+
+    Some((total.checked_add(group)?, next))
+}
+
+/// Every number in the line, written with digits or as english words
+pub fn cardinal_numbers(line: &str) -> Vec<Match> {
+    let tokens = tokenize(line);
+    let mut numbers = Vec::new();
+    let mut index = 0;
+
+    while index < tokens.len() {
+        match number(&tokens, index) {
+            Some((value, next)) => {
+                numbers.push(Match {
+                    start: tokens[index].start,
+                    end: tokens[next - 1].end,
+                    value,
+                });
+                index = next;
+            }
+            None => index += 1,
+        }
+    }
+
+    numbers
+}
+
+/// First and last numbers of the line, which are the same if there is only
+/// one
+pub fn first_and_last_numbers(line: &str) -> Option<(Match, Match)> {
+    let numbers = cardinal_numbers(line);
+
+    Some((*numbers.first()?, *numbers.last()?))
+}
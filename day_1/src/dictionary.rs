@@ -0,0 +1,143 @@
+//! Words the digits can be spelled out with, for the supported languages or
+//! loaded from a file.
+
+use std::str::FromStr;
+
+use aoc_common::ParseError;
+
+use crate::Scanner;
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const SPANISH: [&str; 10] = [
+    "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+const GERMAN: [&str; 10] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+
+/// Names of the built in dictionaries
+pub const LOCALES: [&str; 3] = ["en", "es", "de"];
+
+/// Words mapped to the digit they stand for. A digit can have any number of
+/// words, like "zero" and "nil"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dictionary {
+    words: Vec<(String, usize)>,
+}
+
+impl Dictionary {
+    fn from_words(words: &[&str; 10]) -> Self {
+        Dictionary {
+            words: words.iter().map(|word| word.to_string()).zip(0..).collect(),
+        }
+    }
+
+    pub fn english() -> Self {
+        Dictionary::from_words(&ENGLISH)
+    }
+
+    pub fn spanish() -> Self {
+        Dictionary::from_words(&SPANISH)
+    }
+
+    pub fn german() -> Self {
+        Dictionary::from_words(&GERMAN)
+    }
+
+    /// The built in dictionary of a locale, one of `LOCALES`
+    pub fn locale(name: &str) -> Option<Self> {
+        match name {
+            "en" => Some(Dictionary::english()),
+            "es" => Some(Dictionary::spanish()),
+            "de" => Some(Dictionary::german()),
+            _ => None,
+        }
+    }
+
+    /// Every `(word, digit)` pair
+    pub fn words(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    /// Length in characters of the shortest word
+    pub fn shortest_word(&self) -> Option<usize> {
+        self.words().map(|(word, _)| word.chars().count()).min()
+    }
+
+    /// Length in characters of the longest word
+    pub fn longest_word(&self) -> Option<usize> {
+        self.words().map(|(word, _)| word.chars().count()).max()
+    }
+
+    /// Scanner finding the digits, written as digits or as any of the words
+    pub fn scanner(&self) -> Scanner {
+        let digits = ('0'..='9')
+            .zip(0..)
+            .map(|(digit, value)| (digit.to_string(), value));
+        let words = self.words.iter().cloned();
+
+        Scanner::new(digits.chain(words))
+    }
+}
+
+impl Default for Dictionary {
+    fn default() -> Self {
+        Dictionary::english()
+    }
+}
+
+fn parse_entry(line: &str) -> Result<Vec<(String, usize)>, ParseError> {
+    let Some((digit, words)) = line.split_once(':') else {
+        return Err(ParseError::at_end("expected `<digit>: <word>, ...`", line));
+    };
+
+    let value: usize = aoc_common::parse_token(line, digit.trim(), "invalid digit")?;
+    if value > 9 {
+        return Err(ParseError::at_token(
+            "expected a digit from 0 to 9",
+            line,
+            digit.trim(),
+        ));
+    }
+
+    let words: Vec<(String, usize)> = words
+        .split(',')
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(|word| (word.to_string(), value))
+        .collect();
+
+    if words.is_empty() {
+        return Err(ParseError::at_end("expected at least one word", line));
+    }
+
+    Ok(words)
+}
+
+impl FromStr for Dictionary {
+    type Err = ParseError;
+
+    /// Parses lines like "0: zero, nil". Empty lines and lines starting with
+    /// `#` are skipped
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Vec::new();
+
+        for (number, line) in aoc_common::numbered_lines(s) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry =
+                parse_entry(line).map_err(|error| error.at_line(number).with_source_line(line))?;
+            words.extend(entry);
+        }
+
+        Ok(Dictionary { words })
+    }
+}
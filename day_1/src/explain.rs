@@ -0,0 +1,172 @@
+//! Shows which digits or words were picked on each line, to find out why a
+//! calibration value is not the expected one.
+
+use std::fmt::Write;
+
+use crate::{Match, Scanner};
+
+const FIRST_COLOR: &str = "\x1b[32m";
+const LAST_COLOR: &str = "\x1b[36m";
+/// When the first and last matches share some bytes
+const BOTH_COLOR: &str = "\x1b[35m";
+const RESET: &str = "\x1b[0m";
+
+/// The matches picked on a line and the calibration value they make
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    /// 1 based
+    pub line_number: usize,
+    pub line: &'a str,
+    pub first: Option<Match>,
+    pub last: Option<Match>,
+    /// Pairs of a picked match and another match sharing some of its bytes,
+    /// like "two" and "one" in "twone". Reading the other one instead would
+    /// change the value
+    pub overlaps: Vec<(Match, Match)>,
+}
+
+fn overlap(a: &Match, b: &Match) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Explains the calibration value of a line
+pub fn explain<'a>(line_number: usize, line: &'a str, scanner: &Scanner) -> Explanation<'a> {
+    let first = scanner.find_first(line);
+    let last = scanner.find_last(line);
+
+    let mut overlaps: Vec<(Match, Match)> = Vec::new();
+
+    for picked in first.iter().chain(last.iter()) {
+        for other in scanner.find_all(line) {
+            let pair = (*picked, other);
+            let reversed = (other, *picked);
+
+            if other != *picked
+                && overlap(picked, &other)
+                && !overlaps.contains(&pair)
+                && !overlaps.contains(&reversed)
+            {
+                overlaps.push(pair);
+            }
+        }
+    }
+
+    Explanation {
+        line_number,
+        line,
+        first,
+        last,
+        overlaps,
+    }
+}
+
+impl Explanation<'_> {
+    /// Calibration value made of the first and last digits
+    pub fn value(&self) -> Option<usize> {
+        Some(self.first?.value * 10 + self.last?.value)
+    }
+
+    fn describe(&self, m: &Match) -> String {
+        format!("`{}` at {}..{}", &self.line[m.start..m.end], m.start, m.end)
+    }
+
+    /// Whether the byte at `index` is part of the first and the last match
+    fn picked(&self, index: usize) -> (bool, bool) {
+        let is_in = |m: &Option<Match>| m.is_some_and(|m| (m.start..m.end).contains(&index));
+
+        (is_in(&self.first), is_in(&self.last))
+    }
+
+    /// The line with the first and last matches coloured
+    fn highlighted(&self) -> String {
+        let mut result = String::new();
+        let mut current: Option<&str> = None;
+
+        for (index, c) in self.line.char_indices() {
+            let color = match self.picked(index) {
+                (true, true) => Some(BOTH_COLOR),
+                (true, false) => Some(FIRST_COLOR),
+                (false, true) => Some(LAST_COLOR),
+                (false, false) => None,
+            };
+
+            if color != current {
+                result.push_str(color.unwrap_or(RESET));
+                current = color;
+            }
+
+            result.push(c);
+        }
+
+        if current.is_some() {
+            result.push_str(RESET);
+        }
+
+        result
+    }
+
+    /// Marks under the line: `^` under the first match, `~` under the last
+    /// one and `*` where they share bytes
+    fn markers(&self) -> String {
+        let markers: String = self
+            .line
+            .char_indices()
+            .map(|(index, _)| match self.picked(index) {
+                (true, true) => '*',
+                (true, false) => '^',
+                (false, true) => '~',
+                (false, false) => ' ',
+            })
+            .collect();
+
+        markers.trim_end().to_string()
+    }
+
+    /// Renders the explanation over several lines. Without `color` the
+    /// matches are marked on a line below instead of being coloured
+    pub fn render(&self, color: bool) -> String {
+        let gutter = format!("{}: ", self.line_number);
+        let indent = " ".repeat(gutter.len());
+
+        let mut result = String::new();
+
+        if color {
+            let _ = writeln!(result, "{gutter}{}", self.highlighted());
+        } else {
+            let _ = writeln!(result, "{gutter}{}", self.line);
+
+            let markers = self.markers();
+            if !markers.is_empty() {
+                let _ = writeln!(result, "{indent}{markers}");
+            }
+        }
+
+        match (&self.first, &self.last, self.value()) {
+            (Some(first), Some(last), Some(value)) => {
+                let _ = writeln!(
+                    result,
+                    "{indent}first {} = {}, last {} = {}, value {value}",
+                    self.describe(first),
+                    first.value,
+                    self.describe(last),
+                    last.value
+                );
+            }
+            _ => {
+                let _ = writeln!(result, "{indent}no digit");
+            }
+        }
+
+        for (picked, other) in &self.overlaps {
+            let _ = writeln!(
+                result,
+                "{indent}overlap: {} shares letters with {} = {}",
+                self.describe(picked),
+                self.describe(other),
+                other.value
+            );
+        }
+
+        result
+    }
+}
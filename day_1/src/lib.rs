@@ -0,0 +1,177 @@
+//! Day 1: Trebuchet?!
+
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Solution};
+
+mod cardinal;
+mod dictionary;
+mod explain;
+mod scanner;
+
+pub use cardinal::{cardinal_numbers, first_and_last_numbers};
+pub use dictionary::{Dictionary, LOCALES};
+pub use explain::{explain, Explanation};
+pub use scanner::{Match, Scanner};
+
+/// What to do with the lines that don't have any digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoDigitPolicy {
+    /// Leave the line out
+    Skip,
+    /// Count the line as a calibration value of 0
+    #[default]
+    Zero,
+    /// Fail, pointing at the line
+    Error,
+}
+
+impl FromStr for NoDigitPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(NoDigitPolicy::Skip),
+            "zero" => Ok(NoDigitPolicy::Zero),
+            "error" => Ok(NoDigitPolicy::Error),
+            _ => Err(format!("Invalid policy: {s}")),
+        }
+    }
+}
+
+/// Two digits make the two digit number they spell
+fn join_digits(first: usize, second: usize) -> Option<usize> {
+    Some(first * 10 + second)
+}
+
+/// Concatenates the decimal digits of the numbers: 42 and 103 make 42103
+fn join_numbers(first: usize, second: usize) -> Option<usize> {
+    let shift = 10_usize.checked_pow(second.checked_ilog10().unwrap_or(0) + 1)?;
+
+    first.checked_mul(shift)?.checked_add(second)
+}
+
+/// Calibration values of the lines, numbered from 1 in order, given a way
+/// to find the first and last numbers of a line and to join them
+fn calibration_values<S: AsRef<str>>(
+    lines: &[S],
+    policy: NoDigitPolicy,
+    numbers: impl Fn(&str) -> Option<(usize, usize)>,
+    join: fn(usize, usize) -> Option<usize>,
+) -> Result<Vec<usize>, ParseError> {
+    let mut values = Vec::with_capacity(lines.len());
+
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.as_ref();
+        let error = |message| {
+            ParseError::new(message, line, 0)
+                .with_source_line(line)
+                .at_line(index + 1)
+        };
+
+        match (numbers(line), policy) {
+            (Some((first, second)), _) => {
+                values.push(join(first, second).ok_or_else(|| error("calibration value too big"))?)
+            }
+            (None, NoDigitPolicy::Skip) => {}
+            (None, NoDigitPolicy::Zero) => values.push(0),
+            (None, NoDigitPolicy::Error) => return Err(error("no digit in line")),
+        }
+    }
+
+    Ok(values)
+}
+
+/// Calibration value of every line, made of its first and last digits
+pub fn calibration_values_with_digits<S: AsRef<str>>(
+    lines: &[S],
+    policy: NoDigitPolicy,
+) -> Result<Vec<usize>, ParseError> {
+    calibration_values(
+        lines,
+        policy,
+        |line| {
+            let first = line.bytes().find(u8::is_ascii_digit)?;
+            let second = line.bytes().rev().find(u8::is_ascii_digit)?;
+
+            Some(((first - b'0') as usize, (second - b'0') as usize))
+        },
+        join_digits,
+    )
+}
+
+/// Calibration value of every line, with digits spelled out with the words
+/// of `dictionary`
+pub fn calibration_values_with_dictionary<S: AsRef<str>>(
+    lines: &[S],
+    dictionary: &Dictionary,
+    policy: NoDigitPolicy,
+) -> Result<Vec<usize>, ParseError> {
+    let scanner = dictionary.scanner();
+
+    calibration_values(
+        lines,
+        policy,
+        |line| {
+            aoc_common::trace!("SEARCHING FIRST");
+            let first = scanner.find_first(line)?.value;
+            aoc_common::trace!("SEARCHING SECOND");
+            let second = scanner.find_last(line)?.value;
+            aoc_common::debug!("first: {}, second: {}", first, second);
+
+            Some((first, second))
+        },
+        join_digits,
+    )
+}
+
+/// Calibration value of every line, made of its first and last numbers
+/// written with digits or as english cardinals like "one hundred and three".
+/// The numbers are joined one after the other: 42 and 103 make 42103
+pub fn calibration_values_extended<S: AsRef<str>>(
+    lines: &[S],
+    policy: NoDigitPolicy,
+) -> Result<Vec<usize>, ParseError> {
+    calibration_values(
+        lines,
+        policy,
+        |line| {
+            let (first, last) = first_and_last_numbers(line)?;
+            aoc_common::debug!("first: {}, second: {}", first.value, last.value);
+
+            Some((first.value, last.value))
+        },
+        join_numbers,
+    )
+}
+
+/// Solution to both parts of day 1
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(aoc_common::lines(input)
+            .into_iter()
+            .map(String::from)
+            .collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize, ParseError> {
+        Ok(calibration_values_with_digits(lines, NoDigitPolicy::Zero)?
+            .iter()
+            .sum())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<usize, ParseError> {
+        let values =
+            calibration_values_with_dictionary(lines, &Dictionary::english(), NoDigitPolicy::Zero)?;
+
+        Ok(values.iter().sum())
+    }
+}
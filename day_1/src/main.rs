@@ -1,149 +1,106 @@
-use std::env;
-use std::fs;
-
-fn first_spelled_number_or_digit_to_usize(chars: Vec<char>, reversed: bool) -> usize {
-    const NUMBERS_BY_INDEX: [&str; 10] = [
-        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-    ];
-    const SHORTEST_NUMBER: usize = 3;
-    const LONGEST_NUMBER: usize = 6;
-
-    let chars_len = chars.len();
-    let mut result: usize = 0;
-
-    if reversed {
-        let mut left: usize = chars_len - 1;
-        let mut right: usize = chars_len - 1;
-
-        loop {
-            let char = chars[left];
-
-            if char.is_ascii_digit() {
-                result = char.to_digit(10).unwrap() as usize;
-                break;
-            }
-
-            if right - left + 1 < SHORTEST_NUMBER {
-                left -= 1;
-                continue;
-            }
-
-            let word = chars[left..=right].iter().collect::<String>();
-            let number = NUMBERS_BY_INDEX
-                .iter()
-                .enumerate()
-                .find(|(_, &number)| word.contains(number));
-
-            if let Some(number) = number {
-                result = number.0;
-                break;
-            }
-
-            left -= 1;
-
-            if right - left > LONGEST_NUMBER {
-                right -= 1;
-            }
+use std::io::IsTerminal;
+
+use aoc_common::runner::{self, Cli, Format};
+use aoc_common::Solution;
+use day_1::{Day1, Dictionary, NoDigitPolicy};
+
+const OPTIONS: &str = "    --locale <en|es|de>    language the digits are spelled out in
+    --dictionary <file>    words to use instead, as lines like `0: zero, nil`
+    --explain              show the digits picked on each line for part 2
+    --extended             read whole english numbers like `forty-two` in
+                           part 2, joining the first and last ones
+    --no-digit <policy>    what to do with lines without digits: `zero` (the
+                           default) counts them as 0, `skip` leaves them out
+                           and `error` reports them";
+
+/// Prints the digits picked on every line of every input, and the sum of
+/// the calibration values
+fn explain(cli: &Cli, dictionary: &Dictionary) {
+    let scanner = dictionary.scanner();
+    let color = std::io::stdout().is_terminal();
+
+    let explain_lines = |contents: &str| {
+        let mut lines = String::new();
+        let mut total = 0;
+
+        for (number, line) in aoc_common::numbered_lines(contents) {
+            let explanation = day_1::explain(number, line, &scanner);
+
+            total += explanation.value().unwrap_or_default();
+            lines.push_str(&explanation.render(color));
         }
-    } else {
-        let mut left: usize = 0;
-        let mut right: usize = 0;
-
-        loop {
-            let char = chars[right];
-
-            if char.is_ascii_digit() {
-                result = char.to_digit(10).unwrap() as usize;
-                break;
-            }
-
-            if right - left + 1 < SHORTEST_NUMBER {
-                right += 1;
-                continue;
-            }
 
-            let word = chars[left..=right].iter().collect::<String>();
-            let number = NUMBERS_BY_INDEX
-                .iter()
-                .enumerate()
-                .find(|(_, &number)| word.contains(number));
+        Ok((lines, total))
+    };
+
+    let explained = runner::for_each_input(
+        &cli.inputs(Day1::DEFAULT_INPUT),
+        Format::Text,
+        |filename| runner::parse_file(explain_lines, filename),
+        |_, (lines, total)| {
+            print!("{lines}");
+            println!("Sum: {total}");
+        },
+    );
+
+    if !explained {
+        std::process::exit(1);
+    }
+}
 
-            if let Some(number) = number {
-                result = number.0;
-                break;
+fn main() {
+    let mut dictionary = Dictionary::english();
+    let mut explain_lines = false;
+    let mut policy = NoDigitPolicy::default();
+    let mut extended = false;
+
+    let cli = Cli::from_env(OPTIONS, |flag, args| {
+        match flag {
+            "--locale" => {
+                let locale = runner::flag_value(flag, args)?;
+                dictionary =
+                    Dictionary::locale(&locale).ok_or(format!("Unknown locale: {locale}"))?;
             }
+            "--dictionary" => {
+                let filename = runner::flag_value(flag, args)?;
+                let contents = runner::read_input_or_exit(&filename);
 
-            right += 1;
-
-            if right - left > LONGEST_NUMBER {
-                left += 1;
+                dictionary = contents.parse().unwrap_or_else(|error| {
+                    runner::exit_with_parse_error(error, &filename);
+                });
             }
+            "--explain" => explain_lines = true,
+            "--extended" => extended = true,
+            "--no-digit" => policy = runner::flag_value(flag, args)?.parse()?,
+            _ => return Ok(false),
         }
-    }
-
-    result
-}
-fn calibration_values_with_digits(lines: &[&str]) -> Vec<usize> {
-    let calibration_values: Vec<usize> = lines
-        .iter()
-        .map(|line| {
-            let first = line.chars().find(|c| c.is_ascii_digit()).unwrap_or('0');
-            let second = line
-                .chars()
-                .rev()
-                .find(|c| c.is_ascii_digit())
-                .unwrap_or('0');
-
-            let number = format!("{first}{second}");
-            let number: usize = number.parse().unwrap();
-
-            number
-        })
-        .collect();
-    calibration_values
-}
-
-fn calibration_values_with_spelling(lines: &[&str]) -> Vec<usize> {
-    let calibration_values: Vec<usize> = lines
-        .iter()
-        .map(|line| {
-            let clean_line: Vec<char> = line.chars().collect();
-
-            println!("SEARCHING FIRST");
-            let first: usize = first_spelled_number_or_digit_to_usize(clean_line.clone(), false);
-            println!("SEARCHING SECOND");
-            let second: usize = first_spelled_number_or_digit_to_usize(clean_line.clone(), true);
-            println!("first: {}, second: {}", first, second);
 
-            let number = format!("{first}{second}");
-            let number: usize = number.parse().unwrap();
+        Ok(true)
+    });
 
-            number
-        })
-        .collect();
-
-    calibration_values
-}
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() < 2 {
-        panic!("Please provide a file name");
+    if explain_lines {
+        explain(&cli, &dictionary);
+        return;
     }
 
-    let filename = &args[1];
-    let contents = fs::read_to_string(filename).expect("Something went wrong reading the file");
-
-    let lines: Vec<&str> = contents.lines().map(|line| line.trim()).collect();
-
-    let calibration_values = calibration_values_with_digits(&lines);
-    let calibration_sum: usize = calibration_values.iter().sum();
-
-    println!("Calibration sum: {}", calibration_sum);
-
-    let calibration_values = calibration_values_with_spelling(&lines);
-    let calibration_sum: usize = calibration_values.iter().sum();
-
-    println!("Calibration sum: {}", calibration_sum);
+    cli.run(Day1::DAY, Day1::DEFAULT_INPUT, |contents, parts| {
+        runner::solve_with(
+            contents,
+            parts,
+            Day1::parse,
+            |lines| {
+                Ok(day_1::calibration_values_with_digits(lines, policy)?
+                    .iter()
+                    .sum::<usize>())
+            },
+            |lines| {
+                let values = if extended {
+                    day_1::calibration_values_extended(lines, policy)?
+                } else {
+                    day_1::calibration_values_with_dictionary(lines, &dictionary, policy)?
+                };
+                Ok(values.iter().sum::<usize>())
+            },
+        )
+    });
 }
@@ -0,0 +1,206 @@
+//! Finds the first and last of a set of words in a line with Aho-Corasick
+//! automatons: one pass over the line, no allocations.
+
+use std::collections::VecDeque;
+
+/// A word or digit found in a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// Byte offset of the first byte of the match
+    pub start: usize,
+    /// Byte offset right after the match
+    pub end: usize,
+    pub value: usize,
+}
+
+/// State 0 is the root
+const ROOT: usize = 0;
+
+/// A deterministic automaton over bytes recognizing a set of patterns
+#[derive(Debug, Clone)]
+struct Automaton {
+    transitions: Vec<[u32; 256]>,
+    /// Patterns ending at each state as `(length, value)`, longest first
+    outputs: Vec<Vec<(usize, usize)>>,
+    /// Length of the pattern prefix each state stands for
+    depths: Vec<usize>,
+}
+
+impl Automaton {
+    fn new<'a>(patterns: impl IntoIterator<Item = (&'a [u8], usize)>) -> Self {
+        let mut transitions: Vec<[u32; 256]> = vec![[0; 256]];
+        let mut outputs: Vec<Vec<(usize, usize)>> = vec![Vec::new()];
+        let mut depths: Vec<usize> = vec![0];
+
+        // Trie of the patterns. 0 means there is no edge yet, as nothing
+        // goes back to the root within the trie
+        for (pattern, value) in patterns {
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut state = ROOT;
+
+            for &byte in pattern {
+                let next = transitions[state][byte as usize] as usize;
+
+                state = if next == ROOT {
+                    transitions.push([0; 256]);
+                    outputs.push(Vec::new());
+                    depths.push(depths[state] + 1);
+
+                    let new_state = transitions.len() - 1;
+                    transitions[state][byte as usize] = new_state as u32;
+                    new_state
+                } else {
+                    next
+                };
+            }
+
+            // The first pattern added wins over duplicates
+            if outputs[state].is_empty() {
+                outputs[state].push((pattern.len(), value));
+            }
+        }
+
+        // Breadth first, turn missing edges into the edges of the failure
+        // state, which is always closer to the root
+        let mut failures = vec![ROOT; transitions.len()];
+        let mut queue = VecDeque::new();
+
+        queue.extend(
+            transitions[ROOT]
+                .iter()
+                .map(|&child| child as usize)
+                .filter(|&child| child != ROOT),
+        );
+
+        while let Some(state) = queue.pop_front() {
+            let failure = failures[state];
+
+            // The failure state is a suffix, so its patterns are shorter
+            let suffix_outputs = outputs[failure].clone();
+            outputs[state].extend(suffix_outputs);
+
+            let failure_transitions = transitions[failure];
+
+            for (edge, &fallback) in transitions[state].iter_mut().zip(&failure_transitions) {
+                if *edge as usize == ROOT {
+                    *edge = fallback;
+                } else {
+                    failures[*edge as usize] = fallback as usize;
+                    queue.push_back(*edge as usize);
+                }
+            }
+        }
+
+        Automaton {
+            transitions,
+            outputs,
+            depths,
+        }
+    }
+
+    /// Runs over the bytes and returns the match that starts first, the
+    /// longest one if several start there, as `(start, end, value)`
+    fn find(&self, bytes: impl Iterator<Item = u8>) -> Option<(usize, usize, usize)> {
+        let mut state = ROOT;
+        let mut best: Option<(usize, usize, usize)> = None;
+
+        for (index, byte) in bytes.enumerate() {
+            state = self.transitions[state][byte as usize] as usize;
+            let end = index + 1;
+
+            // Matches found later start at `end - depth` or after, and are
+            // longer if they start at the same byte
+            if best.is_some_and(|(start, _, _)| start < end - self.depths[state]) {
+                break;
+            }
+
+            if let Some(&(length, value)) = self.outputs[state].first() {
+                let start = end - length;
+
+                if best.is_none_or(|(best_start, _, _)| start <= best_start) {
+                    best = Some((start, end, value));
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// Finds the first and last occurrence of a set of words in a line
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    forward: Automaton,
+    /// Recognizes the reversed words, to scan lines from the end
+    backward: Automaton,
+}
+
+impl Scanner {
+    /// Scanner for the given `(word, value)` pairs
+    pub fn new<W: AsRef<str>>(words: impl IntoIterator<Item = (W, usize)>) -> Self {
+        let words: Vec<(W, usize)> = words.into_iter().collect();
+        let reversed: Vec<(Vec<u8>, usize)> = words
+            .iter()
+            .map(|(word, value)| (word.as_ref().bytes().rev().collect(), *value))
+            .collect();
+
+        Scanner {
+            forward: Automaton::new(
+                words
+                    .iter()
+                    .map(|(word, value)| (word.as_ref().as_bytes(), *value)),
+            ),
+            backward: Automaton::new(
+                reversed
+                    .iter()
+                    .map(|(word, value)| (word.as_slice(), *value)),
+            ),
+        }
+    }
+
+    /// Every match, overlapping ones included, ordered by where they end.
+    /// Of several matches ending at the same byte, the longest comes first
+    pub fn find_all<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let automaton = &self.forward;
+
+        line.bytes()
+            .enumerate()
+            .scan(ROOT, move |state, (index, byte)| {
+                *state = automaton.transitions[*state][byte as usize] as usize;
+                Some((index + 1, *state))
+            })
+            .flat_map(move |(end, state)| {
+                automaton.outputs[state]
+                    .iter()
+                    .map(move |&(length, value)| Match {
+                        start: end - length,
+                        end,
+                        value,
+                    })
+            })
+    }
+
+    /// The match that starts first. Of several matches starting at the same
+    /// byte, the longest one
+    pub fn find_first(&self, line: &str) -> Option<Match> {
+        let (start, end, value) = self.forward.find(line.bytes())?;
+
+        Some(Match { start, end, value })
+    }
+
+    /// The match that ends last. Of several matches ending at the same byte,
+    /// the longest one
+    pub fn find_last(&self, line: &str) -> Option<Match> {
+        // Offsets in the reversed line
+        let (start, end, value) = self.backward.find(line.bytes().rev())?;
+
+        Some(Match {
+            start: line.len() - end,
+            end: line.len() - start,
+            value,
+        })
+    }
+}
@@ -0,0 +1,120 @@
+use day_1::{Match, NoDigitPolicy};
+
+fn values(line: &str) -> Vec<usize> {
+    day_1::cardinal_numbers(line)
+        .iter()
+        .map(|number| number.value)
+        .collect()
+}
+
+#[test]
+fn single_words() {
+    assert_eq!(values("zero one nine"), vec![0, 1, 9]);
+    assert_eq!(values("twelve and nineteen"), vec![12, 19]);
+    assert_eq!(values("Seventy"), vec![70]);
+}
+
+#[test]
+fn compound_numbers() {
+    assert_eq!(values("forty-two"), vec![42]);
+    assert_eq!(values("forty two"), vec![42]);
+    assert_eq!(values("one hundred and three"), vec![103]);
+    assert_eq!(values("five hundred"), vec![500]);
+    assert_eq!(values("one hundred thousand"), vec![100_000]);
+    assert_eq!(
+        values("two million three thousand and seven"),
+        vec![2_003_007]
+    );
+    assert_eq!(
+        values("nine hundred ninety-nine thousand nine hundred ninety-nine"),
+        vec![999_999]
+    );
+}
+
+#[test]
+fn numbers_are_split_where_the_grammar_stops() {
+    // Two units in a row are two numbers
+    assert_eq!(values("one two"), vec![1, 2]);
+    // "and" only joins after hundreds and scales
+    assert_eq!(values("one and two"), vec![1, 2]);
+    assert_eq!(values("three thousand thousand"), vec![3_000]);
+    assert_eq!(values("forty, two"), vec![40, 2]);
+    assert_eq!(values("twenty zero"), vec![20, 0]);
+}
+
+#[test]
+fn digits_and_positions() {
+    assert_eq!(values("two1nine"), vec![2, 1, 9]);
+    assert_eq!(values("room 101 on floor twenty-one"), vec![101, 21]);
+
+    let line = "take forty-two, not one hundred and three";
+    assert_eq!(
+        day_1::first_and_last_numbers(line),
+        Some((
+            Match {
+                start: 5,
+                end: 14,
+                value: 42
+            },
+            Match {
+                start: 20,
+                end: 41,
+                value: 103
+            }
+        ))
+    );
+    assert_eq!(day_1::first_and_last_numbers("no numbers here"), None);
+}
+
+#[test]
+fn words_glued_to_other_letters() {
+    assert_eq!(values("abcone2threexyz"), vec![1, 2, 3]);
+    assert_eq!(values("xtwone3four"), vec![2, 3, 4]);
+    assert_eq!(values("7pqrstsixteen"), vec![7, 16]);
+    assert_eq!(values("fortytwo"), vec![42]);
+    assert_eq!(values("OneHundredAndThree"), vec![103]);
+
+    // Read from the left without overlapping: the "wo" left of "two" is not
+    // a number
+    assert_eq!(values("eightwothree"), vec![8, 3]);
+    // Runs of digits are whole numbers
+    assert_eq!(values("zoneight234"), vec![1, 234]);
+
+    assert_eq!(
+        day_1::first_and_last_numbers("xtwone3four"),
+        Some((
+            Match {
+                start: 1,
+                end: 4,
+                value: 2
+            },
+            Match {
+                start: 7,
+                end: 11,
+                value: 4
+            }
+        ))
+    );
+}
+
+#[test]
+fn calibration_joins_first_and_last_numbers() {
+    let lines = [
+        "forty-two then one hundred and three",
+        "just seven",
+        "nothing",
+    ];
+
+    assert_eq!(
+        day_1::calibration_values_extended(&lines, NoDigitPolicy::Zero).unwrap(),
+        vec![42103, 77, 0]
+    );
+
+    let error = day_1::calibration_values_extended(&lines, NoDigitPolicy::Error).unwrap_err();
+    assert_eq!(error.line, Some(3));
+
+    let error =
+        day_1::calibration_values_extended(&["one billion 99999999999"], NoDigitPolicy::Zero)
+            .unwrap_err();
+    assert_eq!(error.message, "calibration value too big");
+}
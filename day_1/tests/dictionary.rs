@@ -0,0 +1,58 @@
+use day_1::{Dictionary, NoDigitPolicy};
+
+fn calibration(line: &str, dictionary: &Dictionary) -> usize {
+    day_1::calibration_values_with_dictionary(&[line], dictionary, NoDigitPolicy::Error).unwrap()[0]
+}
+
+#[test]
+fn built_in_locales() {
+    let spanish = Dictionary::locale("es").unwrap();
+    let german = Dictionary::locale("de").unwrap();
+
+    assert_eq!(calibration("xcuatrodosunox", &spanish), 41);
+    assert_eq!(calibration("achtzweifünfz", &german), 85);
+    assert_eq!(calibration("3nullx", &german), 30);
+    assert_eq!(Dictionary::locale("fr"), None);
+    assert_eq!(Dictionary::locale("en"), Some(Dictionary::default()));
+}
+
+#[test]
+fn word_lengths_are_derived() {
+    assert_eq!(Dictionary::english().shortest_word(), Some(3));
+    assert_eq!(Dictionary::english().longest_word(), Some(5));
+    assert_eq!(Dictionary::spanish().longest_word(), Some(6));
+    assert_eq!(Dictionary::german().shortest_word(), Some(4));
+}
+
+#[test]
+fn dictionary_file_with_aliases() {
+    let dictionary: Dictionary = "# aliases are comma separated
+0: zero, nil
+1: one
+
+7: seven"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        dictionary.words().collect::<Vec<_>>(),
+        vec![("zero", 0), ("nil", 0), ("one", 1), ("seven", 7)]
+    );
+    assert_eq!(calibration("nilxseven", &dictionary), 7);
+    assert_eq!(calibration("onetwonil", &dictionary), 10);
+}
+
+#[test]
+fn invalid_dictionary_lines() {
+    let error = "0: zero\n12: twelve".parse::<Dictionary>().unwrap_err();
+    assert_eq!((error.line, error.token.as_str()), (Some(2), "12"));
+
+    let error = "0: zero\nnine".parse::<Dictionary>().unwrap_err();
+    assert_eq!(error.line, Some(2));
+
+    let error = "x: zero".parse::<Dictionary>().unwrap_err();
+    assert_eq!((error.line, error.column), (Some(1), 0));
+
+    let error = "3: ,".parse::<Dictionary>().unwrap_err();
+    assert_eq!(error.message, "expected at least one word");
+}
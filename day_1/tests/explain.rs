@@ -0,0 +1,62 @@
+use day_1::{Dictionary, Match};
+
+fn matched(start: usize, end: usize, value: usize) -> Match {
+    Match { start, end, value }
+}
+
+#[test]
+fn picked_matches_and_value() {
+    let scanner = Dictionary::english().scanner();
+    let explanation = day_1::explain(1, "two1nine", &scanner);
+
+    assert_eq!(explanation.first, Some(matched(0, 3, 2)));
+    assert_eq!(explanation.last, Some(matched(4, 8, 9)));
+    assert_eq!(explanation.value(), Some(29));
+    assert!(explanation.overlaps.is_empty());
+
+    assert_eq!(
+        explanation.render(false),
+        "1: two1nine
+   ^^^ ~~~~
+   first `two` at 0..3 = 2, last `nine` at 4..8 = 9, value 29
+"
+    );
+}
+
+#[test]
+fn overlapping_words_are_flagged() {
+    let scanner = Dictionary::english().scanner();
+
+    let explanation = day_1::explain(2, "eightwo", &scanner);
+    assert_eq!(explanation.value(), Some(82));
+    assert_eq!(
+        explanation.overlaps,
+        vec![(matched(0, 5, 8), matched(4, 7, 2))]
+    );
+
+    let explanation = day_1::explain(3, "twone", &scanner);
+    assert_eq!(explanation.value(), Some(21));
+    assert_eq!(
+        explanation.render(false),
+        "3: twone
+   ^^*~~
+   first `two` at 0..3 = 2, last `one` at 2..5 = 1, value 21
+   overlap: `two` at 0..3 shares letters with `one` at 2..5 = 1
+"
+    );
+}
+
+#[test]
+fn colors_and_missing_digits() {
+    let scanner = Dictionary::english().scanner();
+
+    let explanation = day_1::explain(1, "a7b", &scanner);
+    assert_eq!(
+        explanation.render(true).lines().next(),
+        Some("1: a\x1b[35m7\x1b[0mb")
+    );
+
+    let explanation = day_1::explain(4, "abc", &scanner);
+    assert_eq!(explanation.value(), None);
+    assert_eq!(explanation.render(false), "4: abc\n   no digit\n");
+}
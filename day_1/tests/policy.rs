@@ -0,0 +1,53 @@
+use day_1::{Dictionary, NoDigitPolicy};
+
+const LINES: [&str; 4] = ["1abc2", "nothing", "treb7uchet", "xtwone3four"];
+
+fn digits(policy: NoDigitPolicy) -> Result<Vec<usize>, aoc_common::ParseError> {
+    day_1::calibration_values_with_digits(&LINES, policy)
+}
+
+fn spelled(policy: NoDigitPolicy) -> Result<Vec<usize>, aoc_common::ParseError> {
+    day_1::calibration_values_with_dictionary(&LINES, &Dictionary::english(), policy)
+}
+
+#[test]
+fn zero_is_the_default() {
+    assert_eq!(NoDigitPolicy::default(), NoDigitPolicy::Zero);
+
+    assert_eq!(digits(NoDigitPolicy::Zero).unwrap(), vec![12, 0, 77, 33]);
+    assert_eq!(spelled(NoDigitPolicy::Zero).unwrap(), vec![12, 0, 77, 24]);
+}
+
+#[test]
+fn skip_leaves_lines_out() {
+    assert_eq!(digits(NoDigitPolicy::Skip).unwrap(), vec![12, 77, 33]);
+    assert_eq!(spelled(NoDigitPolicy::Skip).unwrap(), vec![12, 77, 24]);
+}
+
+#[test]
+fn error_points_at_the_line() {
+    for error in [
+        digits(NoDigitPolicy::Error).unwrap_err(),
+        spelled(NoDigitPolicy::Error).unwrap_err(),
+    ] {
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.source_line.as_deref(), Some("nothing"));
+    }
+
+    // Spelled out digits are enough for the spelled out mode
+    let lines = ["one", "2"];
+    assert!(day_1::calibration_values_with_digits(&lines, NoDigitPolicy::Error).is_err());
+    assert!(day_1::calibration_values_with_dictionary(
+        &lines,
+        &Dictionary::english(),
+        NoDigitPolicy::Error
+    )
+    .is_ok());
+}
+
+#[test]
+fn policy_from_str() {
+    assert_eq!("skip".parse(), Ok(NoDigitPolicy::Skip));
+    assert_eq!("error".parse(), Ok(NoDigitPolicy::Error));
+    assert!("ignore".parse::<NoDigitPolicy>().is_err());
+}
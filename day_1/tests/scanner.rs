@@ -0,0 +1,132 @@
+use day_1::{Dictionary, Match, Scanner};
+
+fn first_and_last(line: &str) -> (usize, usize) {
+    let scanner = Dictionary::english().scanner();
+
+    (
+        scanner.find_first(line).unwrap().value,
+        scanner.find_last(line).unwrap().value,
+    )
+}
+
+#[test]
+fn digits_and_words() {
+    assert_eq!(first_and_last("two1nine"), (2, 9));
+    assert_eq!(first_and_last("abcone2threexyz"), (1, 3));
+    assert_eq!(first_and_last("4nineeightseven2"), (4, 2));
+    assert_eq!(first_and_last("7pqrstsixteen"), (7, 6));
+    assert_eq!(first_and_last("zero"), (0, 0));
+}
+
+#[test]
+fn overlapping_words_count_from_both_ends() {
+    assert_eq!(first_and_last("eightwothree"), (8, 3));
+    assert_eq!(first_and_last("xtwone3four"), (2, 4));
+    assert_eq!(first_and_last("twone"), (2, 1));
+    assert_eq!(first_and_last("oneight"), (1, 8));
+}
+
+#[test]
+fn matches_have_byte_offsets() {
+    let scanner = Dictionary::english().scanner();
+
+    assert_eq!(
+        scanner.find_first("ab7cd"),
+        Some(Match {
+            start: 2,
+            end: 3,
+            value: 7
+        })
+    );
+    assert_eq!(
+        scanner.find_last("xeightwoy"),
+        Some(Match {
+            start: 5,
+            end: 8,
+            value: 2
+        })
+    );
+    assert_eq!(scanner.find_first("no digits"), None);
+    assert_eq!(scanner.find_last(""), None);
+}
+
+#[test]
+fn custom_words() {
+    let scanner = Scanner::new([("cone", 9), ("one", 1), ("fünf", 5)]);
+
+    assert_eq!(scanner.find_first("xcone").map(|m| m.value), Some(9));
+    assert_eq!(scanner.find_first("onecone").map(|m| m.value), Some(1));
+    assert_eq!(
+        scanner.find_last("xfünfone").map(|m| (m.start, m.value)),
+        Some((6, 1))
+    );
+    assert_eq!(
+        scanner.find_last("fünfx").map(|m| (m.start, m.end)),
+        Some((0, 5))
+    );
+}
+
+#[test]
+fn words_nested_in_other_words() {
+    let scanner = Scanner::new([("cones", 5), ("one", 1)]);
+
+    let first = scanner.find_first("xconesx").unwrap();
+    assert_eq!((first.start, first.end, first.value), (1, 6, 5));
+
+    let last = scanner.find_last("xconesx").unwrap();
+    assert_eq!((last.start, last.end, last.value), (1, 6, 5));
+
+    // The inner word is found when the outer one isn't complete
+    assert_eq!(scanner.find_first("xconex").map(|m| m.value), Some(1));
+    assert_eq!(scanner.find_last("conexone").map(|m| m.start), Some(5));
+}
+
+#[test]
+fn first_and_last_match_a_naive_search() {
+    let words = [("ab", 1), ("b", 2), ("bcd", 3), ("abcde", 4), ("c", 5)];
+    let scanner = Scanner::new(words);
+
+    let matches = |line: &str| -> Vec<Match> {
+        let mut matches = Vec::new();
+        for start in 0..line.len() {
+            for (word, value) in words {
+                if line[start..].starts_with(word) {
+                    let end = start + word.len();
+                    matches.push(Match { start, end, value });
+                }
+            }
+        }
+        matches
+    };
+
+    // Every line of up to 6 letters out of "abcde"
+    let mut lines = vec![String::new()];
+    for _ in 0..6 {
+        let longer: Vec<String> = lines
+            .iter()
+            .filter(|line| line.len() == lines.last().unwrap().len())
+            .flat_map(|line| "abcde".chars().map(move |c| format!("{line}{c}")))
+            .collect();
+        lines.extend(longer);
+    }
+
+    for line in &lines {
+        let all = matches(line);
+        let first = all.iter().min_by_key(|m| (m.start, usize::MAX - m.end));
+        let last = all.iter().max_by_key(|m| (m.end, usize::MAX - m.start));
+
+        assert_eq!(scanner.find_first(line).as_ref(), first, "{line}");
+        assert_eq!(scanner.find_last(line).as_ref(), last, "{line}");
+    }
+}
+
+#[test]
+fn all_matches_including_overlaps() {
+    let scanner = Dictionary::english().scanner();
+    let values: Vec<(usize, usize)> = scanner
+        .find_all("twone3eightwo")
+        .map(|m| (m.start, m.value))
+        .collect();
+
+    assert_eq!(values, vec![(0, 2), (2, 1), (5, 3), (6, 8), (10, 2)]);
+}
@@ -0,0 +1,147 @@
+//! How many cubes of each color there are, either in the bag or in a draw.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_common::ParseError;
+
+/// Counts of cubes by color. Colors that are not listed have no cubes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, usize>,
+}
+
+impl Cubes {
+    pub fn new() -> Self {
+        Cubes::default()
+    }
+
+    /// The same cubes, with `count` cubes of `color`
+    pub fn with(mut self, color: &str, count: usize) -> Self {
+        self.counts.insert(color.to_string(), count);
+        self
+    }
+
+    /// Number of cubes of `color`, 0 if there are none
+    pub fn get(&self, color: &str) -> usize {
+        self.counts.get(color).copied().unwrap_or_default()
+    }
+
+    /// Keeps the largest of the current and the given count
+    pub fn raise(&mut self, color: &str, count: usize) {
+        let current = self.counts.entry(color.to_string()).or_default();
+        *current = (*current).max(count);
+    }
+
+    /// Every `(color, count)`, sorted by color
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.counts
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Whether there are at least as many cubes of every color as in `other`
+    pub fn contains(&self, other: &Cubes) -> bool {
+        other.iter().all(|(color, count)| count <= self.get(color))
+    }
+}
+
+/// Writes the cubes as "3 blue, 4 red"
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, (color, count)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{count} {color}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses comma separated `<count> <color>` pairs, like "3 blue, 4 red".
+/// Errors point into `line`, which `s` is a slice of
+pub(crate) fn parse_cubes(line: &str, s: &str) -> Result<Cubes, ParseError> {
+    let mut cubes = Cubes::new();
+
+    for part in s.split(',') {
+        if part.trim().is_empty() {
+            continue;
+        }
+
+        let mut words = part.split_whitespace();
+
+        let (Some(count), Some(color), None) = (words.next(), words.next(), words.next()) else {
+            return Err(ParseError::at_token(
+                "expected `<count> <color>`",
+                line,
+                part.trim(),
+            ));
+        };
+
+        let count: usize = aoc_common::parse_token(line, count, "invalid cube count")?;
+
+        if cubes.counts.insert(color.to_string(), count).is_some() {
+            return Err(ParseError::at_token(
+                format!("color `{color}` listed twice"),
+                line,
+                color,
+            ));
+        }
+    }
+
+    Ok(cubes)
+}
+
+/// The cubes the elf put in the bag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag(pub Cubes);
+
+impl Default for Bag {
+    /// The bag of the puzzle: 12 red, 13 green and 14 blue cubes
+    fn default() -> Self {
+        Bag(Cubes::new()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14))
+    }
+}
+
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    /// Parses `<count> <color>` pairs separated by commas or new lines, like
+    /// "12 red, 13 green, 14 blue". Lines starting with `#` are skipped
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cubes = Cubes::new();
+
+        for (number, line) in aoc_common::numbered_lines(s) {
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let tag = |error: ParseError| error.at_line(number).with_source_line(line);
+
+            for (color, count) in parse_cubes(line, line).map_err(tag)?.iter() {
+                if cubes.counts.insert(color.to_string(), count).is_some() {
+                    let error =
+                        ParseError::at_token(format!("color `{color}` listed twice"), line, color);
+                    return Err(tag(error));
+                }
+            }
+        }
+
+        Ok(Bag(cubes))
+    }
+}
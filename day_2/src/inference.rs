@@ -0,0 +1,144 @@
+//! What the bag most likely holds, given the draws of a game.
+//!
+//! Each draw takes some cubes out of the bag at random, without replacement,
+//! and puts them back before the next one. The chance of a draw is then
+//! multivariate hypergeometric: the number of ways to pick its cubes of each
+//! color, over the number of ways to pick that many cubes out of the bag.
+
+use crate::{Bag, Cubes, Game};
+
+/// Natural log of the binomial coefficient `n choose k`. `None` if `k > n`,
+/// as there is no way to pick them
+fn ln_binomial(n: usize, k: usize) -> Option<f64> {
+    if k > n {
+        return None;
+    }
+
+    let k = k.min(n - k);
+
+    Some(
+        (0..k)
+            .map(|i| ((n - i) as f64).ln() - ((i + 1) as f64).ln())
+            .sum(),
+    )
+}
+
+impl Game {
+    /// Log of the probability of seeing all the draws of the game if they
+    /// were taken out of `bag`. `None` if the bag can't hold one of them
+    pub fn log_likelihood(&self, bag: &Bag) -> Option<f64> {
+        let total = bag.0.total();
+        let mut log_likelihood = 0.0;
+
+        for draw in &self.draws {
+            for (color, count) in draw.iter() {
+                log_likelihood += ln_binomial(bag.0.get(color), count)?;
+            }
+
+            log_likelihood -= ln_binomial(total, draw.total())?;
+        }
+
+        Some(log_likelihood)
+    }
+
+    /// The bag of `total` cubes under which the draws are the most likely.
+    /// `None` if there are fewer cubes than the game needs at once, or if the
+    /// game saw no cubes while the bag has some: it has no color to fill
+    ///
+    /// Only colors that were seen are in the bag: a cube of another color
+    /// would only make the seen ones rarer
+    pub fn most_likely_bag(&self, total: usize) -> Option<Bag> {
+        let seen = self.max_seen();
+        let spare = total.checked_sub(seen.total())?;
+
+        if spare == 0 {
+            return Some(Bag(seen));
+        } else if seen.total() == 0 {
+            return None;
+        }
+
+        // The log likelihood is a sum of one concave function per color, so
+        // the best bag gives the spare cubes the largest gains of all colors.
+        // Rather than handing them out one by one, search for the lowest
+        // gain they all beat, as a float's bits order like the float itself
+        let counts_above = |threshold: u64| -> Vec<usize> {
+            seen.iter()
+                .map(|(color, count)| {
+                    self.count_above(color, count, total, f64::from_bits(threshold))
+                })
+                .collect()
+        };
+        let fits = |counts: &[usize]| {
+            counts
+                .iter()
+                .try_fold(0usize, |sum, &count| sum.checked_add(count))
+                .is_some_and(|sum| sum <= total)
+        };
+
+        let mut low = 0;
+        let mut high = seen
+            .iter()
+            .map(|(color, count)| self.gain(color, count))
+            .fold(0.0, f64::max)
+            .to_bits();
+
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+
+            if fits(&counts_above(middle)) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        // The cubes left over all gain exactly the `high` threshold, so
+        // which colors get them doesn't change the likelihood
+        let mut counts = counts_above(high);
+        let mut left = total - counts.iter().sum::<usize>();
+
+        for (count, tied) in counts.iter_mut().zip(counts_above(low)) {
+            let extra = (tied - *count).min(left);
+            *count += extra;
+            left -= extra;
+        }
+
+        let bag = seen
+            .colors()
+            .zip(counts)
+            .fold(Cubes::new(), |bag, (color, count)| bag.with(color, count));
+
+        Some(Bag(bag))
+    }
+
+    /// How many cubes of `color` a bag starting with `count` of them ends up
+    /// with when it takes every cube gaining more than `threshold`, up to
+    /// `total`
+    fn count_above(&self, color: &str, count: usize, total: usize, threshold: f64) -> usize {
+        let (mut low, mut high) = (count, total);
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            if self.gain(color, middle) <= threshold {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+
+        low
+    }
+
+    /// How much the log likelihood grows when a bag holding `count` cubes of
+    /// `color` gets one more
+    fn gain(&self, color: &str, count: usize) -> f64 {
+        // ln((count + 1) / (count + 1 - drawn)), precise even for huge counts
+        self.draws
+            .iter()
+            .map(|draw| draw.get(color))
+            .filter(|&drawn| drawn > 0)
+            .map(|drawn| -(-(drawn as f64) / (count as f64 + 1.0)).ln_1p())
+            .sum()
+    }
+}
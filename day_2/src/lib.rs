@@ -0,0 +1,168 @@
+//! Day 2: Cube Conundrum
+
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Solution};
+
+mod bag;
+mod inference;
+mod report;
+
+pub use bag::{Bag, Cubes};
+pub use report::{Excess, Report, ReportFormat, Verdict};
+
+/// The color of a maximum, and the draw that first reached it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Maximum<'a> {
+    pub color: &'a str,
+    pub count: usize,
+    /// Index of the draw in the game, from 0
+    pub draw: usize,
+}
+
+/// A game of cubes: the cubes of every draw, in order
+#[derive(Debug)]
+pub struct Game {
+    pub id: usize,
+    pub draws: Vec<Cubes>,
+}
+
+impl Game {
+    /// The most cubes of each color seen at once
+    pub fn max_seen(&self) -> Cubes {
+        let mut max_seen = Cubes::new();
+
+        for (color, count) in self.draws.iter().flat_map(Cubes::iter) {
+            max_seen.raise(color, count);
+        }
+
+        max_seen
+    }
+
+    /// Number of cubes in each draw
+    pub fn draw_totals(&self) -> Vec<usize> {
+        self.draws.iter().map(Cubes::total).collect()
+    }
+
+    /// For every color, the most cubes seen at once and the first draw that
+    /// showed that many, sorted by color
+    pub fn maximums(&self) -> Vec<Maximum<'_>> {
+        let mut maximums: Vec<Maximum> = Vec::new();
+
+        for (draw, cubes) in self.draws.iter().enumerate() {
+            for (color, count) in cubes.iter() {
+                match maximums.iter_mut().find(|maximum| maximum.color == color) {
+                    Some(maximum) if maximum.count < count => {
+                        maximum.count = count;
+                        maximum.draw = draw;
+                    }
+                    Some(_) => {}
+                    None => maximums.push(Maximum { color, count, draw }),
+                }
+            }
+        }
+
+        maximums.sort_by(|a, b| a.color.cmp(b.color));
+        maximums
+    }
+
+    /// Indices of the draws that could not have been taken out of `bag`
+    pub fn impossible_draws(&self, bag: &Bag) -> Vec<usize> {
+        self.draws
+            .iter()
+            .enumerate()
+            .filter(|(_, cubes)| !bag.0.contains(cubes))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Whether every draw could have been taken out of `bag`, that is
+    /// whether they have any chance of happening with it
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.log_likelihood(bag).is_some()
+    }
+
+    /// Product of the fewest cubes of each color the bag could have held.
+    /// `colors` are counted even if they were never seen, which makes the
+    /// power 0
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> usize {
+        let max_seen = self.max_seen();
+        let mut all_colors: Vec<&str> = max_seen.colors().collect();
+
+        for color in colors {
+            all_colors.push(color);
+        }
+
+        all_colors.sort_unstable();
+        all_colors.dedup();
+
+        all_colors.iter().map(|color| max_seen.get(color)).product()
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+    /// Parses a string into a Game
+    /// The string is in the form "Game #<id>: #n red, #n green, #n blue; #n red, #n green, #n blue;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((game_id, leaks)) = s.split_once(':') else {
+            return Err(ParseError::at_end("expected `:` after the game id", s));
+        };
+
+        let Some(game_id) = game_id.strip_prefix("Game ") else {
+            return Err(ParseError::at_token("expected `Game <id>:`", s, game_id));
+        };
+
+        let id = aoc_common::parse_token(s, game_id.trim(), "invalid game id")?;
+
+        let draws = leaks
+            .split(';')
+            .map(|leak| {
+                if leak.trim().is_empty() {
+                    return Err(ParseError::at_token("expected `<count> <color>`", s, leak));
+                }
+
+                bag::parse_cubes(s, leak)
+            })
+            .collect::<Result<Vec<Cubes>, ParseError>>()?;
+
+        Ok(Game { id, draws })
+    }
+}
+
+/// Sum of the ids of the games possible with `bag`
+pub fn possible_games_id_sum(games: &[Game], bag: &Bag) -> usize {
+    games
+        .iter()
+        .filter(|game| game.is_possible_with(bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// Sum of the powers of the games, counting every color of `bag`
+pub fn power_sum(games: &[Game], bag: &Bag) -> usize {
+    games.iter().map(|game| game.power(bag.0.colors())).sum()
+}
+
+/// Solution to both parts of day 2
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+    type Input = Vec<Game>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::parse_lines(input)
+    }
+
+    fn part1(games: &Self::Input) -> Result<usize, ParseError> {
+        Ok(possible_games_id_sum(games, &Bag::default()))
+    }
+
+    fn part2(games: &Self::Input) -> Result<usize, ParseError> {
+        Ok(power_sum(games, &Bag::default()))
+    }
+}
@@ -1,117 +1,203 @@
-use std::env;
-use std::fs;
-use std::str::FromStr;
-
-#[derive(Debug)]
-struct Game {
-    id: usize,
-    max_red_seen: usize,
-    max_green_seen: usize,
-    max_blue_seen: usize,
+use aoc_common::runner::{self, Cli, Format};
+use aoc_common::Solution;
+use day_2::{Bag, Day2, Game, Report, ReportFormat};
+
+const OPTIONS: &str =
+    "    --bag <cubes>          cubes in the bag, like `12 red, 13 green, 14 blue`
+    --bag-file <file>      read the cubes in the bag from a file
+    --why <id>             show the draws of a game and why the bag can or
+                           can't hold them
+    --infer <total>        show the most likely bag of `total` cubes for
+                           every game, and how likely the draws are with it
+                           and with the given bag
+    --report <format>      list the impossible games with the cubes that
+                           didn't fit, and the possible ones with their
+                           minimal bag, as a `table` or `csv`";
+
+/// Prints the draws of the game with the given id, marking the ones the bag
+/// couldn't hold, and which draw showed the most cubes of each color
+fn why(cli: &Cli, bag: &Bag, id: usize) {
+    let [filename] = cli.inputs(Day2::DEFAULT_INPUT)[..] else {
+        eprintln!("--why takes a single input");
+        std::process::exit(2);
+    };
+
+    let games = runner::parse_file(Day2::parse, filename).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        std::process::exit(1);
+    });
+
+    let Some(game) = games.iter().find(|game| game.id == id) else {
+        eprintln!("No game {id} in {}", aoc_common::input_name(filename));
+        std::process::exit(1);
+    };
+
+    print!("{}", explain(game, bag));
 }
 
-impl FromStr for Game {
-    type Err = Box<dyn std::error::Error>;
-    /// Parses a string into a Game
-    /// The string is in the form "Game #<id>: #n red, #n green, #n blue; #n red, #n green, #n blue;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut game = Game {
-            id: 0,
-            max_red_seen: 0,
-            max_green_seen: 0,
-            max_blue_seen: 0,
-        };
-
-        const GAME_ID_START: usize = 5;
-        let game_id_end = s.find(':').unwrap();
-
-        let game_id: usize = s[GAME_ID_START..game_id_end].parse()?;
-        game.id = game_id;
-
-        let leaks = s[game_id_end + 1..].split(';').collect::<Vec<&str>>();
-
-        for leak in leaks {
-            let leak = leak.trim();
-            let leak_parts = leak.split(',').collect::<Vec<&str>>();
-
-            let color_counts =
-                leak_parts
-                    .iter()
-                    .map(|part| part.trim().split(' '))
-                    .map(|mut part| {
-                        let count = part.next().unwrap().parse::<usize>().unwrap();
-                        let color = part.next().unwrap().trim();
-
-                        (color, count)
-                    });
-
-            for (color, count) in color_counts {
-                match color {
-                    "red" => {
-                        if count > game.max_red_seen {
-                            game.max_red_seen = count;
-                        }
-                    }
-                    "green" => {
-                        if count > game.max_green_seen {
-                            game.max_green_seen = count;
-                        }
-                    }
-                    "blue" => {
-                        if count > game.max_blue_seen {
-                            game.max_blue_seen = count;
-                        }
-                    }
-                    _ => {
-                        panic!("Unknown color: {}", color);
+/// Prints which games of every input the bag allows
+fn report(cli: &Cli, bag: &Bag, format: ReportFormat) {
+    let inputs: Vec<(&str, Vec<Game>)> = cli
+        .inputs(Day2::DEFAULT_INPUT)
+        .into_iter()
+        .map(|filename| {
+            let contents = runner::read_input_or_exit(filename);
+            let games = Day2::parse(&contents).unwrap_or_else(|error| {
+                runner::exit_with_parse_error(error, filename);
+            });
+
+            (aoc_common::input_name(filename), games)
+        })
+        .collect();
+
+    let reports: Vec<Report> = inputs
+        .iter()
+        .map(|(input, games)| Report::new(input, games, bag))
+        .collect();
+
+    print!("{}", Report::render_all(&reports, format));
+}
+
+/// Prints the most likely bag of `total` cubes for every game of every input
+fn infer(cli: &Cli, bag: &Bag, total: usize) {
+    let describe = |log_likelihood: Option<f64>| {
+        log_likelihood.map_or("impossible".to_string(), |value| format!("{value:.3}"))
+    };
+
+    let inferred = runner::for_each_input(
+        &cli.inputs(Day2::DEFAULT_INPUT),
+        Format::Text,
+        |filename| runner::parse_file(Day2::parse, filename),
+        |_, games| {
+            for game in &games {
+                match game.most_likely_bag(total) {
+                    Some(likely) => println!(
+                        "Game {}: {} (log likelihood {}, given bag {})",
+                        game.id,
+                        likely.0,
+                        describe(game.log_likelihood(&likely)),
+                        describe(game.log_likelihood(bag))
+                    ),
+                    None if game.max_seen().total() == 0 => {
+                        println!("Game {}: saw no cubes to fill the bag with", game.id)
                     }
+                    None => println!(
+                        "Game {}: needs at least {} cubes",
+                        game.id,
+                        game.max_seen().total()
+                    ),
                 }
             }
-        }
+        },
+    );
 
-        Ok(game)
+    if !inferred {
+        std::process::exit(1);
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+fn explain(game: &Game, bag: &Bag) -> String {
+    let mut lines = Vec::new();
 
-    if args.len() < 2 {
-        panic!("Please provide a file name");
+    if game.is_possible_with(bag) {
+        lines.push(format!("Game {} is possible with {}", game.id, bag.0));
+    } else {
+        lines.push(format!("Game {} is impossible with {}", game.id, bag.0));
     }
 
-    let filename = &args[1];
-    let contents = fs::read_to_string(filename).expect("Something went wrong reading the file");
+    let excesses = game.excesses(bag);
 
-    let lines: Vec<&str> = contents.lines().map(|line| line.trim()).collect();
+    for ((index, draw), total) in game.draws.iter().enumerate().zip(game.draw_totals()) {
+        let mut line = format!("  draw {}: {draw} ({total} cubes)", index + 1);
 
-    let mut games: Vec<Game> = lines
-        .iter()
-        .map(|line| line.parse::<Game>())
-        .flatten()
-        // .inspect(|game| println!("{:?}", game))
-        .collect();
+        let exceeded: Vec<String> = excesses
+            .iter()
+            .filter(|excess| excess.draw == index)
+            .map(|excess| format!("{} {} > {}", excess.count, excess.color, excess.limit))
+            .collect();
 
-    const MAX_RED: usize = 12;
-    const MAX_GREEN: usize = 13;
-    const MAX_BLUE: usize = 14;
+        if !exceeded.is_empty() {
+            line.push_str(&format!(", too many: {}", exceeded.join(", ")));
+        }
 
-    let valid_games: Vec<&Game> = games
-        .iter()
-        .filter(|game| {
-            game.max_red_seen <= MAX_RED
-                && game.max_green_seen <= MAX_GREEN
-                && game.max_blue_seen <= MAX_BLUE
-        })
-        .collect();
+        lines.push(line);
+    }
 
-    let id_sum = valid_games.iter().map(|game| game.id).sum::<usize>();
-    println!("Valid games: {:?}", id_sum);
+    for maximum in game.maximums() {
+        lines.push(format!(
+            "  most {}: {} in draw {}",
+            maximum.color,
+            maximum.count,
+            maximum.draw + 1
+        ));
+    }
 
-    let total_power = games
-        .iter()
-        .map(|game| game.max_red_seen * game.max_green_seen * game.max_blue_seen)
-        .sum::<usize>();
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+fn main() {
+    let mut bag = Bag::default();
+    let mut why_game = None;
+    let mut infer_total = None;
+    let mut report_format = None;
+
+    let cli = Cli::from_env(OPTIONS, |flag, args| {
+        match flag {
+            "--bag" => {
+                let cubes = runner::flag_value(flag, args)?;
+                bag = cubes
+                    .parse()
+                    .map_err(|error: aoc_common::ParseError| error.in_file("--bag").diagnostic())?;
+            }
+            "--bag-file" => {
+                let filename = runner::flag_value(flag, args)?;
+                let contents = runner::read_input_or_exit(&filename);
+
+                bag = contents.parse().unwrap_or_else(|error| {
+                    runner::exit_with_parse_error(error, &filename);
+                });
+            }
+            "--why" => {
+                let id = runner::flag_value(flag, args)?;
+                why_game = Some(id.parse().map_err(|_| format!("Invalid game id: {id}"))?);
+            }
+            "--infer" => {
+                let total = runner::flag_value(flag, args)?;
+                infer_total = Some(
+                    total
+                        .parse()
+                        .map_err(|_| format!("Invalid number of cubes: {total}"))?,
+                );
+            }
+            "--report" => report_format = Some(runner::flag_value(flag, args)?.parse()?),
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    });
+
+    if let Some(id) = why_game {
+        why(&cli, &bag, id);
+        return;
+    }
+
+    if let Some(format) = report_format {
+        report(&cli, &bag, format);
+        return;
+    }
+
+    if let Some(total) = infer_total {
+        infer(&cli, &bag, total);
+        return;
+    }
 
-    println!("Total power: {:?}", total_power);
+    cli.run(Day2::DAY, Day2::DEFAULT_INPUT, |contents, parts| {
+        runner::solve_with(
+            contents,
+            parts,
+            Day2::parse,
+            |games| Ok(day_2::possible_games_id_sum(games, &bag)),
+            |games| Ok(day_2::power_sum(games, &bag)),
+        )
+    });
 }
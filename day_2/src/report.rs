@@ -0,0 +1,230 @@
+//! A report of which games a bag allows, to audit bag configurations:
+//! impossible games with every color that didn't fit, and possible ones
+//! with the smallest bag they need.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::{Bag, Cubes, Game};
+
+/// More cubes of a color in a draw than the bag holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Excess<'a> {
+    /// Index of the draw in the game, from 0
+    pub draw: usize,
+    pub color: &'a str,
+    pub count: usize,
+    /// Cubes of that color in the bag
+    pub limit: usize,
+}
+
+impl Excess<'_> {
+    /// How many cubes too many there were
+    pub fn margin(&self) -> usize {
+        self.count - self.limit
+    }
+}
+
+impl Game {
+    /// Every color of every draw that the bag doesn't have enough cubes of,
+    /// in the order of the draws
+    pub fn excesses(&self, bag: &Bag) -> Vec<Excess<'_>> {
+        self.draws
+            .iter()
+            .enumerate()
+            .flat_map(|(draw, cubes)| {
+                cubes
+                    .iter()
+                    .filter(|&(color, count)| count > bag.0.get(color))
+                    .map(move |(color, count)| Excess {
+                        draw,
+                        color,
+                        count,
+                        limit: bag.0.get(color),
+                    })
+            })
+            .collect()
+    }
+}
+
+/// What a bag means for a game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict<'a> {
+    /// The game is possible and needs at least these cubes
+    Possible(Cubes),
+    Impossible(Vec<Excess<'a>>),
+}
+
+/// The verdict of a bag on every game of an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<'a> {
+    /// Name of the input the games come from
+    pub input: String,
+    pub games: Vec<(usize, Verdict<'a>)>,
+}
+
+/// How to write a report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Columns aligned for reading
+    #[default]
+    Table,
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(ReportFormat::Table),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => Err(format!("Unknown report format: {s}")),
+        }
+    }
+}
+
+const HEADER: [&str; 8] = [
+    "game",
+    "status",
+    "draw",
+    "color",
+    "count",
+    "limit",
+    "margin",
+    "minimal bag",
+];
+
+impl<'a> Report<'a> {
+    pub fn new(input: &str, games: &'a [Game], bag: &Bag) -> Self {
+        let games = games
+            .iter()
+            .map(|game| {
+                let excesses = game.excesses(bag);
+
+                let verdict = if excesses.is_empty() {
+                    Verdict::Possible(game.max_seen())
+                } else {
+                    Verdict::Impossible(excesses)
+                };
+
+                (game.id, verdict)
+            })
+            .collect();
+
+        Report {
+            input: input.to_string(),
+            games,
+        }
+    }
+
+    /// One row per possible game and one per excess of an impossible game,
+    /// with the columns of `HEADER`. Draws are numbered from 1
+    fn rows(&self) -> Vec<[String; 8]> {
+        let mut rows = Vec::new();
+
+        for (id, verdict) in &self.games {
+            match verdict {
+                Verdict::Possible(minimal_bag) => rows.push([
+                    id.to_string(),
+                    "possible".to_string(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    minimal_bag.to_string(),
+                ]),
+                Verdict::Impossible(excesses) => {
+                    for excess in excesses {
+                        rows.push([
+                            id.to_string(),
+                            "impossible".to_string(),
+                            (excess.draw + 1).to_string(),
+                            excess.color.to_string(),
+                            excess.count.to_string(),
+                            excess.limit.to_string(),
+                            excess.margin().to_string(),
+                            String::new(),
+                        ]);
+                    }
+                }
+            }
+        }
+
+        rows
+    }
+
+    pub fn render(&self, format: ReportFormat) -> String {
+        Report::render_all(std::slice::from_ref(self), format)
+    }
+
+    /// Renders the reports of several inputs. Tables are labelled with
+    /// their input when there is more than one of them, while CSV has a
+    /// single header and an `input` column
+    pub fn render_all(reports: &[Report], format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Table if reports.len() > 1 => reports
+                .iter()
+                .map(|report| format!("{}:\n{}", report.input, report.table()))
+                .collect(),
+            ReportFormat::Table => reports.iter().map(Report::table).collect(),
+            ReportFormat::Csv => csv(reports),
+        }
+    }
+
+    fn table(&self) -> String {
+        let rows = self.rows();
+        let mut widths = HEADER.map(str::len);
+
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let header = HEADER.map(str::to_string);
+        let mut result = String::new();
+
+        for row in std::iter::once(&header).chain(&rows) {
+            let line: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect();
+
+            let _ = writeln!(result, "{}", line.join("  ").trim_end());
+        }
+
+        result
+    }
+}
+
+/// The rows of every report as CSV, after a header. The first column is
+/// the input of the row
+fn csv(reports: &[Report]) -> String {
+    let mut result = String::new();
+    let header = HEADER.map(|column| column.replace(' ', "_"));
+    let _ = writeln!(result, "input,{}", header.join(","));
+
+    for report in reports {
+        for row in report.rows() {
+            let fields: Vec<String> = std::iter::once(&report.input)
+                .chain(&row)
+                .map(|field| csv_field(field))
+                .collect();
+            let _ = writeln!(result, "{}", fields.join(","));
+        }
+    }
+
+    result
+}
+
+/// Quotes a field if it has a comma, a quote or a line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
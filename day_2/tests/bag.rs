@@ -0,0 +1,64 @@
+use day_2::{Bag, Cubes, Game};
+
+fn game(line: &str) -> Game {
+    line.parse().unwrap()
+}
+
+#[test]
+fn any_color_is_tracked() {
+    let game = game("Game 7: 3 blue, 4 red; 1 red, 2 yellow, 6 blue; 2 yellow");
+
+    assert_eq!(game.id, 7);
+    assert_eq!(
+        game.max_seen(),
+        Cubes::new()
+            .with("blue", 6)
+            .with("red", 4)
+            .with("yellow", 2)
+    );
+}
+
+#[test]
+fn feasibility_with_custom_bags() {
+    let game = game("Game 1: 3 blue, 4 red; 2 yellow");
+
+    assert!(game.is_possible_with(&"4 red, 3 blue, 2 yellow".parse().unwrap()));
+    assert!(!game.is_possible_with(&"4 red, 3 blue, 1 yellow".parse().unwrap()));
+    // A color missing from the bag has no cubes
+    assert!(!game.is_possible_with(&Bag::default()));
+}
+
+#[test]
+fn power_counts_the_given_colors() {
+    let game = game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+
+    assert_eq!(game.power(Bag::default().0.colors()), 48);
+    assert_eq!(game.power(["red"]), 48);
+    // Never seen, so the bag may have none
+    assert_eq!(game.power(["yellow"]), 0);
+}
+
+#[test]
+fn bag_from_a_config_file() {
+    let bag: Bag = "# the elf's bag
+12 red, 13 green
+14 blue
+"
+    .parse()
+    .unwrap();
+
+    assert_eq!(bag, Bag::default());
+    assert_eq!(bag.0.total(), 39);
+}
+
+#[test]
+fn invalid_bags() {
+    let error = "12 red\n13 red".parse::<Bag>().unwrap_err();
+    assert_eq!((error.line, error.column), (Some(2), 3));
+
+    let error = "12 red, green".parse::<Bag>().unwrap_err();
+    assert_eq!(error.message, "expected `<count> <color>`");
+
+    let error = "Game 1: 3 blue, 2 blue".parse::<Game>().unwrap_err();
+    assert_eq!(error.message, "color `blue` listed twice");
+}
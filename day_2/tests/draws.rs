@@ -0,0 +1,73 @@
+use day_2::{Bag, Cubes, Game, Maximum};
+
+fn game(line: &str) -> Game {
+    line.parse().unwrap()
+}
+
+#[test]
+fn draws_are_kept_in_order() {
+    let game = game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red");
+
+    assert_eq!(
+        game.draws,
+        vec![
+            Cubes::new()
+                .with("green", 8)
+                .with("blue", 6)
+                .with("red", 20),
+            Cubes::new()
+                .with("blue", 5)
+                .with("red", 4)
+                .with("green", 13),
+            Cubes::new().with("green", 5).with("red", 1),
+        ]
+    );
+    assert_eq!(game.draw_totals(), vec![34, 22, 6]);
+}
+
+#[test]
+fn first_draw_reaching_each_maximum() {
+    let game = game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green, 4 red");
+
+    assert_eq!(
+        game.maximums(),
+        vec![
+            Maximum {
+                color: "blue",
+                count: 6,
+                draw: 1
+            },
+            Maximum {
+                color: "green",
+                count: 2,
+                draw: 1
+            },
+            Maximum {
+                color: "red",
+                count: 4,
+                draw: 0
+            },
+        ]
+    );
+}
+
+#[test]
+fn impossible_draws() {
+    let game = game("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red");
+
+    assert_eq!(game.impossible_draws(&Bag::default()), vec![2]);
+    assert!(!game.is_possible_with(&Bag::default()));
+
+    let bag: Bag = "14 red, 3 green, 15 blue".parse().unwrap();
+    assert!(game.impossible_draws(&bag).is_empty());
+    assert!(game.is_possible_with(&bag));
+}
+
+#[test]
+fn cubes_display() {
+    assert_eq!(
+        Cubes::new().with("red", 4).with("blue", 3).to_string(),
+        "3 blue, 4 red"
+    );
+    assert_eq!(Cubes::new().to_string(), "");
+}
@@ -0,0 +1,113 @@
+use day_2::{Bag, Cubes, Game};
+
+fn game(line: &str) -> Game {
+    line.parse().unwrap()
+}
+
+fn bag(red: usize, blue: usize) -> Bag {
+    Bag(Cubes::new().with("red", red).with("blue", blue))
+}
+
+#[test]
+fn likelihood_of_a_single_draw() {
+    let game = game("Game 1: 1 red, 1 blue");
+
+    // 2 red and 2 blue: 2 * 2 ways out of 4 choose 2 = 6
+    let log_likelihood = game.log_likelihood(&bag(2, 2)).unwrap();
+    assert!((log_likelihood - (4.0f64 / 6.0).ln()).abs() < 1e-9);
+}
+
+#[test]
+fn likelihood_multiplies_over_draws() {
+    let game = game("Game 1: 1 red; 1 red");
+
+    // 1 red out of 3 cubes, twice
+    let log_likelihood = game.log_likelihood(&bag(1, 2)).unwrap();
+    assert!((log_likelihood - (1.0f64 / 9.0).ln()).abs() < 1e-9);
+}
+
+#[test]
+fn impossible_bags_have_no_likelihood() {
+    let game = game("Game 1: 3 red, 1 blue");
+
+    assert_eq!(game.log_likelihood(&bag(2, 5)), None);
+    assert!(!game.is_possible_with(&bag(2, 5)));
+    assert!(game.is_possible_with(&bag(3, 1)));
+}
+
+#[test]
+fn most_likely_bag_beats_every_other_bag() {
+    let game = game("Game 1: 3 red, 1 blue; 2 red; 1 red, 2 blue");
+    let total = 12;
+
+    let best = (3..=total - 2)
+        .map(|red| bag(red, total - red))
+        .max_by(|a, b| {
+            let a = game.log_likelihood(a).unwrap();
+            let b = game.log_likelihood(b).unwrap();
+            a.total_cmp(&b)
+        })
+        .unwrap();
+
+    assert_eq!(game.most_likely_bag(total), Some(best));
+}
+
+#[test]
+fn most_likely_bag_needs_enough_cubes() {
+    let game = game("Game 1: 3 red, 1 blue; 2 blue");
+
+    assert_eq!(game.most_likely_bag(4), None);
+    assert_eq!(game.most_likely_bag(5), Some(bag(3, 2)));
+}
+
+#[test]
+fn most_likely_bag_for_every_total() {
+    let game = game("Game 1: 3 red, 1 blue; 2 red; 1 red, 2 blue");
+
+    for total in 5..60 {
+        let best = (3..=total - 2)
+            .map(|red| bag(red, total - red))
+            .max_by(|a, b| {
+                let a = game.log_likelihood(a).unwrap();
+                let b = game.log_likelihood(b).unwrap();
+                a.total_cmp(&b)
+            })
+            .unwrap();
+        let likely = game.most_likely_bag(total).unwrap();
+
+        assert_eq!(likely.0.total(), total);
+        assert!(
+            (game.log_likelihood(&likely).unwrap() - game.log_likelihood(&best).unwrap()).abs()
+                < 1e-9,
+            "{total} cubes: {likely:?}, not {best:?}"
+        );
+    }
+}
+
+#[test]
+fn most_likely_bag_of_many_cubes() {
+    let game = game("Game 1: 3 red, 1 blue; 2 red; 1 red, 2 blue");
+
+    // Red was drawn twice as much as blue, so it gets about twice the cubes
+    let likely = game.most_likely_bag(1_000_000_000).unwrap();
+    let red = likely.0.get("red");
+    assert_eq!(likely.0.total(), 1_000_000_000);
+    assert!(red.abs_diff(666_666_667) < 10, "{red} red");
+
+    let likely = game.most_likely_bag(usize::MAX).unwrap();
+    assert_eq!(likely.0.total(), usize::MAX);
+}
+
+#[test]
+fn most_likely_bag_needs_seen_cubes() {
+    let game = Game {
+        id: 1,
+        draws: vec![Cubes::new().with("red", 0)],
+    };
+
+    assert_eq!(
+        game.most_likely_bag(0),
+        Some(Bag(Cubes::new().with("red", 0)))
+    );
+    assert_eq!(game.most_likely_bag(3), None);
+}
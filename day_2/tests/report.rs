@@ -0,0 +1,110 @@
+use day_2::{Bag, Cubes, Day2, Excess, Report, ReportFormat, Verdict};
+
+use aoc_common::Solution;
+
+const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red";
+
+#[test]
+fn excesses_name_the_draw_color_and_margin() {
+    let games = Day2::parse(GAMES).unwrap();
+
+    let excesses = games[1].excesses(&Bag::default());
+    assert_eq!(
+        excesses,
+        vec![
+            Excess {
+                draw: 2,
+                color: "blue",
+                count: 15,
+                limit: 14
+            },
+            Excess {
+                draw: 2,
+                color: "red",
+                count: 14,
+                limit: 12
+            },
+        ]
+    );
+    assert_eq!(excesses[1].margin(), 2);
+    assert!(games[0].excesses(&Bag::default()).is_empty());
+}
+
+#[test]
+fn possible_games_come_with_their_minimal_bag() {
+    let games = Day2::parse(GAMES).unwrap();
+    let report = Report::new("games.txt", &games, &Bag::default());
+
+    assert_eq!(
+        report.games[0],
+        (
+            1,
+            Verdict::Possible(Cubes::new().with("blue", 6).with("green", 2).with("red", 4))
+        )
+    );
+    assert!(matches!(report.games[1], (4, Verdict::Impossible(_))));
+}
+
+#[test]
+fn table() {
+    let games = Day2::parse(GAMES).unwrap();
+    let report = Report::new("games.txt", &games, &Bag::default());
+
+    assert_eq!(
+        report.render(ReportFormat::Table),
+        "\
+game  status      draw  color  count  limit  margin  minimal bag
+1     possible                                       6 blue, 2 green, 4 red
+4     impossible  3     blue   15     14     1
+4     impossible  3     red    14     12     2
+"
+    );
+}
+
+#[test]
+fn csv() {
+    let games = Day2::parse(GAMES).unwrap();
+    let report = Report::new("games.txt", &games, &Bag::default());
+
+    assert_eq!(
+        report.render(ReportFormat::Csv),
+        "\
+input,game,status,draw,color,count,limit,margin,minimal_bag
+games.txt,1,possible,,,,,,\"6 blue, 2 green, 4 red\"
+games.txt,4,impossible,3,blue,15,14,1,
+games.txt,4,impossible,3,red,14,12,2,
+"
+    );
+}
+
+#[test]
+fn several_inputs() {
+    let games = Day2::parse(GAMES).unwrap();
+    let reports = [
+        Report::new("first.txt", &games[..1], &Bag::default()),
+        Report::new("second, with a comma.txt", &games[1..], &Bag::default()),
+    ];
+
+    // A single header, and every row says where it comes from
+    assert_eq!(
+        Report::render_all(&reports, ReportFormat::Csv),
+        "\
+input,game,status,draw,color,count,limit,margin,minimal_bag
+first.txt,1,possible,,,,,,\"6 blue, 2 green, 4 red\"
+\"second, with a comma.txt\",4,impossible,3,blue,15,14,1,
+\"second, with a comma.txt\",4,impossible,3,red,14,12,2,
+"
+    );
+
+    let table = Report::render_all(&reports, ReportFormat::Table);
+    assert!(table.starts_with("first.txt:\ngame  status"));
+    assert!(table.contains("\nsecond, with a comma.txt:\ngame  status"));
+}
+
+#[test]
+fn report_formats() {
+    assert_eq!("table".parse(), Ok(ReportFormat::Table));
+    assert_eq!("csv".parse(), Ok(ReportFormat::Csv));
+    assert!("xml".parse::<ReportFormat>().is_err());
+}
@@ -0,0 +1,154 @@
+//! The graph linking every symbol to the numbers around it, written for
+//! other tools: Graphviz DOT or JSON. Numbers touching no symbol are
+//! included, without edges, and numbers touching several symbols have an
+//! edge to each of them.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use aoc_common::json;
+
+use crate::{Schematic, Token, TokenValue};
+
+/// How to write the graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Dot,
+    Json,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(ExportFormat::Dot),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(format!("Unknown export format: {s}")),
+        }
+    }
+}
+
+/// Quotes a string for DOT, where only quotes and backslashes need escaping
+fn dot_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// DOT id of a token, made of its kind and position
+fn dot_id(token: &Token) -> String {
+    let kind = match token.value {
+        TokenValue::Number(_) => "number",
+        TokenValue::Symbol(_) => "symbol",
+    };
+
+    format!("{kind}_{}_{}", token.line_number, token.start)
+}
+
+impl Schematic {
+    /// Edges of the graph as indices in `tokens`: `(symbol, number)` for
+    /// every symbol around every number, sorted by symbol
+    fn edges(&self) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| token.number().is_some())
+            .flat_map(|(number, token)| {
+                self.adjacent_symbol_indices(token)
+                    .map(move |symbol| (symbol, number))
+            })
+            .collect();
+
+        edges.sort_unstable();
+        edges
+    }
+
+    pub fn export(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Dot => self.to_dot(),
+            ExportFormat::Json => self.to_json(),
+        }
+    }
+
+    /// The graph in Graphviz DOT. Nodes carry their `row`, `col` and
+    /// `length`, and a pinned `pos` laying them out like the schematic
+    pub fn to_dot(&self) -> String {
+        let mut result = String::from("graph schematic {\n");
+
+        for token in &self.tokens {
+            let (label, shape) = match token.value {
+                TokenValue::Number(number) => (number.to_string(), "box"),
+                TokenValue::Symbol(c) => (c.to_string(), "circle"),
+            };
+
+            let _ = writeln!(
+                result,
+                "    {} [label={}, shape={shape}, row={}, col={}, length={}, pos=\"{},-{}!\"];",
+                dot_id(token),
+                dot_quote(&label),
+                token.line_number,
+                token.start,
+                token.length,
+                token.start,
+                token.line_number
+            );
+        }
+
+        for (symbol, number) in self.edges() {
+            let _ = writeln!(
+                result,
+                "    {} -- {};",
+                dot_id(&self.tokens[symbol]),
+                dot_id(&self.tokens[number])
+            );
+        }
+
+        result.push_str("}\n");
+        result
+    }
+
+    /// The graph in JSON. Numbers and symbols have the index of their token
+    /// as `id`, which edges refer to
+    pub fn to_json(&self) -> String {
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+
+        for (id, token) in self.tokens.iter().enumerate() {
+            let position = format!(
+                r#""row":{},"col":{},"length":{}"#,
+                token.line_number, token.start, token.length
+            );
+
+            match token.value {
+                TokenValue::Number(number) => {
+                    numbers.push(format!(r#"{{"id":{id},"value":{number},{position}}}"#));
+                }
+                TokenValue::Symbol(c) => symbols.push(format!(
+                    r#"{{"id":{id},"symbol":{},{position}}}"#,
+                    json::quote(&c.to_string())
+                )),
+            }
+        }
+
+        let edges: Vec<String> = self
+            .edges()
+            .iter()
+            .map(|(symbol, number)| format!(r#"{{"symbol":{symbol},"number":{number}}}"#))
+            .collect();
+
+        let list = |items: &[String]| {
+            if items.is_empty() {
+                "[]".to_string()
+            } else {
+                format!("[\n    {}\n  ]", items.join(",\n    "))
+            }
+        };
+
+        format!(
+            "{{\n  \"numbers\": {},\n  \"symbols\": {},\n  \"edges\": {}\n}}\n",
+            list(&numbers),
+            list(&symbols),
+            list(&edges)
+        )
+    }
+}
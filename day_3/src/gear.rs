@@ -0,0 +1,116 @@
+//! Which symbols count as gears and how their ratio is worked out.
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::{Node, TokenValue};
+
+/// How the numbers around a gear make its ratio
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Reducer {
+    #[default]
+    Product,
+    Sum,
+}
+
+impl Reducer {
+    /// The numbers reduced to one, `None` if it doesn't fit in a `usize`
+    pub fn reduce(&self, mut numbers: impl Iterator<Item = usize>) -> Option<usize> {
+        match self {
+            Reducer::Product => numbers.try_fold(1, usize::checked_mul),
+            Reducer::Sum => numbers.try_fold(0, usize::checked_add),
+        }
+    }
+}
+
+impl FromStr for Reducer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Reducer::Product),
+            "sum" => Ok(Reducer::Sum),
+            _ => Err(format!("Unknown reducer: {s}")),
+        }
+    }
+}
+
+/// What makes a symbol a gear: being one of `symbols` and touching a number
+/// of numbers within `arity`. Its ratio is the `reducer` of those numbers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: Vec<char>,
+    pub arity: RangeInclusive<usize>,
+    pub reducer: Reducer,
+}
+
+/// The puzzle's rule: a `*` touching exactly two numbers, whose ratio is
+/// their product
+impl Default for GearRule {
+    fn default() -> Self {
+        GearRule {
+            symbols: vec!['*'],
+            arity: 2..=2,
+            reducer: Reducer::Product,
+        }
+    }
+}
+
+impl GearRule {
+    /// Whether the symbol of the node is a gear
+    pub fn matches(&self, node: &Node) -> bool {
+        let TokenValue::Symbol(c) = node.value.value else {
+            return false;
+        };
+
+        self.symbols.contains(&c) && self.arity.contains(&node.children.len())
+    }
+
+    /// Ratio of the gear, `None` if the node is not one or if the ratio
+    /// doesn't fit in a `usize`
+    pub fn ratio(&self, node: &Node) -> Option<usize> {
+        if !self.matches(node) {
+            return None;
+        }
+
+        self.reducer
+            .reduce(node.children.iter().filter_map(|token| token.number()))
+    }
+}
+
+/// Parses an arity like `2`, `2..=4`, `2..5` or `3..`. A range without a
+/// start starts at 1, as symbols touching no number are never gears
+pub fn parse_arity(s: &str) -> Result<RangeInclusive<usize>, String> {
+    let invalid = || format!("Invalid arity: {s}");
+    let number = |n: &str| n.parse::<usize>().map_err(|_| invalid());
+
+    let (start, end) = match s.split_once("..") {
+        None => {
+            let arity = number(s)?;
+            (arity, arity)
+        }
+        Some((start, end)) => {
+            let start = if start.is_empty() { 1 } else { number(start)? };
+
+            let end = if let Some(end) = end.strip_prefix('=') {
+                number(end)?
+            } else if end.is_empty() {
+                usize::MAX
+            } else {
+                number(end)?.checked_sub(1).ok_or_else(invalid)?
+            };
+
+            (start, end)
+        }
+    };
+
+    if start == 0 {
+        return Err(format!("{}, a gear touches at least one number", invalid()));
+    }
+
+    if start > end {
+        return Err(invalid());
+    }
+
+    Ok(start..=end)
+}
@@ -0,0 +1,298 @@
+//! Day 3: Gear Ratios
+
+use std::ops::Range;
+use std::str::FromStr;
+
+use aoc_common::grid::{Grid, Position};
+use aoc_common::{ParseError, Solution};
+
+mod export;
+mod gear;
+
+pub use export::ExportFormat;
+pub use gear::{parse_arity, GearRule, Reducer};
+
+/// Something found in the engine schematic: a part number or a symbol
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+pub enum TokenValue {
+    Number(usize),
+    Symbol(char),
+}
+
+/// A number or symbol along with its position in the schematic
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+pub struct Token {
+    pub value: TokenValue,
+    pub start: usize,
+    pub length: usize,
+    pub line_number: usize,
+}
+
+/// A symbol along with the numbers connected to it
+#[derive(Debug)]
+pub struct Node<'a> {
+    pub value: &'a Token,
+    pub children: Vec<&'a Token>,
+}
+
+impl Token {
+    /// The value of the token, if it is a number
+    pub fn number(&self) -> Option<usize> {
+        match self.value {
+            TokenValue::Number(number) => Some(number),
+            TokenValue::Symbol(_) => None,
+        }
+    }
+
+    /// Grid cells covered by this token
+    pub fn cells(&self) -> impl Iterator<Item = Position> + '_ {
+        (self.start..self.start + self.length).map(|col| (self.line_number, col))
+    }
+
+    /// Whether the token covers the cell at `position`
+    pub fn covers(&self, (row, col): Position) -> bool {
+        row == self.line_number && (self.start..self.start + self.length).contains(&col)
+    }
+
+    /// Rows and columns of the token's bounding box grown by one cell on
+    /// every side. They go one past the last row and column of the grid at
+    /// its edges
+    pub fn expanded_box(&self) -> (Range<usize>, Range<usize>) {
+        (
+            self.line_number.saturating_sub(1)..self.line_number + 2,
+            self.start.saturating_sub(1)..self.start + self.length + 1,
+        )
+    }
+
+    /// Whether the tokens are adjacent, diagonals included: a cell of `other`
+    /// is in the expanded bounding box of this token
+    pub fn is_adjacent_to(&self, other: &Token) -> bool {
+        let (rows, cols) = self.expanded_box();
+
+        rows.contains(&other.line_number)
+            && cols.start < other.start + other.length
+            && other.start < cols.end
+    }
+
+    /// Cells of the expanded bounding box that fit in a grid of `width` by
+    /// `height` cells, apart from the token's own cells
+    pub fn ring(&self, width: usize, height: usize) -> impl Iterator<Item = Position> + '_ {
+        let (rows, cols) = self.expanded_box();
+        let rows = rows.start..rows.end.min(height);
+        let cols = cols.start..cols.end.min(width);
+
+        rows.flat_map(move |row| cols.clone().map(move |col| (row, col)))
+            .filter(|&cell| !self.covers(cell))
+    }
+}
+
+/// Points an error at the cell `start` of `row`, the line `line_number` of
+/// the schematic counting from 0. Columns of errors are in bytes
+fn locate_in_row(error: ParseError, row: &[char], start: usize, line_number: usize) -> ParseError {
+    let column = row[..start].iter().map(|c| c.len_utf8()).sum();
+
+    ParseError { column, ..error }
+        .with_source_line(&String::from_iter(row))
+        .at_line(line_number + 1)
+}
+
+/// Splits a schematic row into its numbers and symbols. Dots are skipped.
+/// Fails on numbers too big for a `usize`
+pub fn row_to_tokens(row: &[char], line_number: usize) -> Result<Vec<Token>, ParseError> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut col = 0;
+
+    while col < row.len() {
+        let c = row[col];
+
+        if c.is_ascii_digit() {
+            let length = row[col..].iter().take_while(|c| c.is_ascii_digit()).count();
+            let digits = &row[col..col + length];
+            let number = digits
+                .iter()
+                .try_fold(0usize, |number, digit| {
+                    number
+                        .checked_mul(10)?
+                        .checked_add(digit.to_digit(10)? as usize)
+                })
+                .ok_or_else(|| {
+                    let error = ParseError::new("number too big", String::from_iter(digits), 0);
+                    locate_in_row(error, row, col, line_number)
+                })?;
+
+            tokens.push(Token {
+                value: TokenValue::Number(number),
+                start: col,
+                length,
+                line_number,
+            });
+
+            col += length;
+            continue;
+        }
+
+        if c != '.' {
+            tokens.push(Token {
+                value: TokenValue::Symbol(c),
+                start: col,
+                length: 1,
+                line_number,
+            });
+        }
+
+        col += 1;
+    }
+
+    Ok(tokens)
+}
+
+/// Splits a schematic line into its numbers and symbols. Dots are skipped
+pub fn line_to_tokens(line: &str, line_number: usize) -> Result<Vec<Token>, ParseError> {
+    let row: Vec<char> = line.chars().collect();
+    row_to_tokens(&row, line_number)
+}
+
+/// The engine schematic, both as a grid of characters and as the numbers
+/// and symbols found in it
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub tokens: Vec<Token>,
+    /// Index in `tokens` of the symbol on each cell
+    pub symbol_index: Grid<Option<usize>>,
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_chars(s)?;
+        let mut tokens: Vec<Token> = Vec::new();
+
+        for (line_number, row) in grid.rows().enumerate() {
+            tokens.extend(row_to_tokens(row, line_number)?);
+        }
+
+        let mut symbol_index = Grid::new(grid.width(), grid.height(), None);
+
+        for (index, token) in tokens.iter().enumerate() {
+            if let TokenValue::Symbol(_) = token.value {
+                symbol_index[(token.line_number, token.start)] = Some(index);
+            }
+        }
+
+        Ok(Schematic {
+            grid,
+            tokens,
+            symbol_index,
+        })
+    }
+}
+
+impl Schematic {
+    pub fn numbers(&self) -> impl Iterator<Item = &Token> {
+        self.tokens.iter().filter(|token| token.number().is_some())
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = &Token> {
+        self.tokens
+            .iter()
+            .filter(|token| matches!(token.value, TokenValue::Symbol(_)))
+    }
+
+    /// Indices in `tokens` of the symbols around `token`, in reading order
+    fn adjacent_symbol_indices<'a>(&'a self, token: &'a Token) -> impl Iterator<Item = usize> + 'a {
+        token
+            .ring(self.grid.width(), self.grid.height())
+            .filter_map(|cell| self.symbol_index[cell])
+    }
+
+    /// Symbols adjacent to `token`, including diagonally, in reading order
+    pub fn adjacent_symbols<'a>(&'a self, token: &'a Token) -> impl Iterator<Item = &'a Token> {
+        self.adjacent_symbol_indices(token)
+            .map(|index| &self.tokens[index])
+    }
+
+    /// Every number of the schematic adjacent to a symbol
+    pub fn part_numbers(&self) -> Vec<&Token> {
+        self.numbers()
+            .filter(|number| self.adjacent_symbols(number).next().is_some())
+            .collect()
+    }
+
+    /// Every symbol connected to at least one number, along with those
+    /// numbers. A number touching several symbols is a child of each of them
+    pub fn nodes(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Vec<&Token>> = vec![Vec::new(); self.tokens.len()];
+
+        for number in self.numbers() {
+            for index in self.adjacent_symbol_indices(number) {
+                children[index].push(number);
+            }
+        }
+
+        self.tokens
+            .iter()
+            .zip(children)
+            .filter(|(_, children)| !children.is_empty())
+            .map(|(value, children)| Node { value, children })
+            .collect()
+    }
+
+    /// Sum of the gear ratios: the product of the two numbers of every `*`
+    /// connected to exactly two numbers
+    pub fn gear_ratios_sum(&self) -> Result<usize, ParseError> {
+        self.gear_ratios_sum_with(&GearRule::default())
+    }
+
+    /// Sum of the ratios of the gears as defined by `rule`. Fails if a ratio
+    /// or the sum doesn't fit in a `usize`, pointing at the gear
+    pub fn gear_ratios_sum_with(&self, rule: &GearRule) -> Result<usize, ParseError> {
+        let mut sum: usize = 0;
+
+        for node in self.nodes().iter().filter(|node| rule.matches(node)) {
+            let gear = node.value;
+            let too_big = |message: &str| {
+                let row = self.grid.row(gear.line_number).unwrap_or_default();
+                let error = ParseError::new(message, row[gear.start].to_string(), 0);
+                locate_in_row(error, row, gear.start, gear.line_number)
+            };
+
+            let ratio = rule
+                .ratio(node)
+                .ok_or_else(|| too_big("gear ratio too big"))?;
+            sum = sum
+                .checked_add(ratio)
+                .ok_or_else(|| too_big("sum of the gear ratios too big"))?;
+        }
+
+        Ok(sum)
+    }
+}
+
+/// Solution to both parts of day 3
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+    type Input = Schematic;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<usize, ParseError> {
+        Ok(schematic
+            .part_numbers()
+            .iter()
+            .filter_map(|token| token.number())
+            .sum())
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<usize, ParseError> {
+        schematic.gear_ratios_sum()
+    }
+}
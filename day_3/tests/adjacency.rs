@@ -0,0 +1,118 @@
+use day_3::{Schematic, Token};
+
+fn part_values(schematic: &str) -> Vec<usize> {
+    let schematic: Schematic = schematic.parse().unwrap();
+
+    schematic
+        .part_numbers()
+        .iter()
+        .filter_map(|token| token.number())
+        .collect()
+}
+
+/// A 7 by 5 schematic with `123` in the middle and a `#` at `(row, col)`
+fn with_symbol_at(row: usize, col: usize) -> String {
+    let mut lines: Vec<Vec<char>> = vec![vec!['.'; 7]; 5];
+    lines[2][2..5].copy_from_slice(&['1', '2', '3']);
+    lines[row][col] = '#';
+
+    lines
+        .iter()
+        .map(|line| line.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[test]
+fn every_cell_of_the_expanded_box() {
+    for row in 1..=3 {
+        for col in 1..=5 {
+            if row == 2 && (2..5).contains(&col) {
+                continue;
+            }
+
+            assert_eq!(
+                part_values(&with_symbol_at(row, col)),
+                vec![123],
+                "symbol at {row}, {col}"
+            );
+        }
+    }
+}
+
+#[test]
+fn nothing_outside_the_expanded_box() {
+    for row in 0..5 {
+        for col in 0..7 {
+            if (1..=3).contains(&row) && (1..=5).contains(&col) {
+                continue;
+            }
+
+            assert!(
+                part_values(&with_symbol_at(row, col)).is_empty(),
+                "symbol at {row}, {col}"
+            );
+        }
+    }
+}
+
+#[test]
+fn numbers_at_line_ends() {
+    // Touching the symbol at the start or the end of their own line
+    assert_eq!(part_values("12*\n...\n"), vec![12]);
+    assert_eq!(part_values("*12\n...\n"), vec![12]);
+
+    // Diagonally, from the last column and from the first one
+    assert_eq!(part_values("..12\n.*..\n"), vec![12]);
+    assert_eq!(part_values("12..\n..*.\n"), vec![12]);
+    assert_eq!(part_values("..*\n12.\n"), vec![12]);
+
+    // Rows don't wrap around: the end of a line is not next to the start of
+    // the following one
+    assert!(part_values("..12\n*...\n").is_empty());
+    assert!(part_values("...*\n12..\n").is_empty());
+}
+
+#[test]
+fn first_and_last_rows() {
+    assert_eq!(part_values("5..\n.$.\n"), vec![5]);
+    assert_eq!(part_values(".$.\n..5\n"), vec![5]);
+    assert_eq!(part_values("5\n$\n"), vec![5]);
+}
+
+#[test]
+fn number_touching_two_symbols() {
+    let schematic: Schematic = "*..\n.42\n..#".parse().unwrap();
+    let number = &schematic.tokens[1];
+
+    let symbols: Vec<&Token> = schematic.adjacent_symbols(number).collect();
+    assert_eq!(symbols, vec![&schematic.tokens[0], &schematic.tokens[2]]);
+
+    // Still one part number, but a child of both symbols
+    assert_eq!(schematic.part_numbers(), vec![number]);
+
+    let nodes = schematic.nodes();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0].value, &schematic.tokens[0]);
+    assert_eq!(nodes[0].children, vec![number]);
+    assert_eq!(nodes[1].value, &schematic.tokens[2]);
+    assert_eq!(nodes[1].children, vec![number]);
+}
+
+#[test]
+fn adjacency_goes_both_ways() {
+    let schematic: Schematic = "1.....\n.23...\n...*..\n....45".parse().unwrap();
+
+    for a in &schematic.tokens {
+        for b in &schematic.tokens {
+            assert_eq!(a.is_adjacent_to(b), b.is_adjacent_to(a), "{a:?} {b:?}");
+        }
+    }
+
+    let [one, twenty_three, star, forty_five] = [0, 1, 2, 3].map(|i| &schematic.tokens[i]);
+
+    assert!(one.is_adjacent_to(twenty_three));
+    assert!(twenty_three.is_adjacent_to(star));
+    assert!(star.is_adjacent_to(forty_five));
+    assert!(!one.is_adjacent_to(star));
+    assert!(!twenty_three.is_adjacent_to(forty_five));
+}
@@ -0,0 +1,95 @@
+use day_3::{ExportFormat, Schematic};
+
+const SCHEMATIC: &str = "467..\n...*.\n..35.\n.....\n.9..\"\n";
+
+fn schematic(s: &str) -> Schematic {
+    s.parse().unwrap()
+}
+
+#[test]
+fn dot() {
+    assert_eq!(
+        schematic(SCHEMATIC).export(ExportFormat::Dot),
+        r#"graph schematic {
+    number_0_0 [label="467", shape=box, row=0, col=0, length=3, pos="0,-0!"];
+    symbol_1_3 [label="*", shape=circle, row=1, col=3, length=1, pos="3,-1!"];
+    number_2_2 [label="35", shape=box, row=2, col=2, length=2, pos="2,-2!"];
+    number_4_1 [label="9", shape=box, row=4, col=1, length=1, pos="1,-4!"];
+    symbol_4_4 [label="\"", shape=circle, row=4, col=4, length=1, pos="4,-4!"];
+    symbol_1_3 -- number_0_0;
+    symbol_1_3 -- number_2_2;
+}
+"#
+    );
+}
+
+#[test]
+fn json() {
+    assert_eq!(
+        schematic(SCHEMATIC).export(ExportFormat::Json),
+        r#"{
+  "numbers": [
+    {"id":0,"value":467,"row":0,"col":0,"length":3},
+    {"id":2,"value":35,"row":2,"col":2,"length":2},
+    {"id":3,"value":9,"row":4,"col":1,"length":1}
+  ],
+  "symbols": [
+    {"id":1,"symbol":"*","row":1,"col":3,"length":1},
+    {"id":4,"symbol":"\"","row":4,"col":4,"length":1}
+  ],
+  "edges": [
+    {"symbol":1,"number":0},
+    {"symbol":1,"number":2}
+  ]
+}
+"#
+    );
+}
+
+#[test]
+fn json_without_symbols() {
+    assert_eq!(
+        schematic("12..\n....").to_json(),
+        r#"{
+  "numbers": [
+    {"id":0,"value":12,"row":0,"col":0,"length":2}
+  ],
+  "symbols": [],
+  "edges": []
+}
+"#
+    );
+}
+
+#[test]
+fn export_formats() {
+    assert_eq!("dot".parse(), Ok(ExportFormat::Dot));
+    assert_eq!("json".parse(), Ok(ExportFormat::Json));
+    assert!("svg".parse::<ExportFormat>().is_err());
+}
+
+#[test]
+fn number_touching_two_symbols() {
+    let schematic = schematic("*.*\n.5.");
+
+    assert_eq!(
+        schematic.to_dot(),
+        r#"graph schematic {
+    symbol_0_0 [label="*", shape=circle, row=0, col=0, length=1, pos="0,-0!"];
+    symbol_0_2 [label="*", shape=circle, row=0, col=2, length=1, pos="2,-0!"];
+    number_1_1 [label="5", shape=box, row=1, col=1, length=1, pos="1,-1!"];
+    symbol_0_0 -- number_1_1;
+    symbol_0_2 -- number_1_1;
+}
+"#
+    );
+
+    assert!(schematic.to_json().ends_with(
+        r#""edges": [
+    {"symbol":0,"number":2},
+    {"symbol":1,"number":2}
+  ]
+}
+"#
+    ));
+}
@@ -0,0 +1,106 @@
+use day_3::{GearRule, Reducer, Schematic};
+
+const SCHEMATIC: &str = "\
+2.3.4
+.*..#
+5...6
+..*..
+..7..
+";
+
+fn sum_with(symbols: &str, arity: &str, reducer: Reducer) -> usize {
+    let schematic: Schematic = SCHEMATIC.parse().unwrap();
+
+    schematic
+        .gear_ratios_sum_with(&GearRule {
+            symbols: symbols.chars().collect(),
+            arity: day_3::parse_arity(arity).unwrap(),
+            reducer,
+        })
+        .unwrap()
+}
+
+#[test]
+fn default_rule() {
+    let schematic: Schematic = "1.2\n.*.\n...\n3*4".parse().unwrap();
+
+    assert_eq!(schematic.gear_ratios_sum_with(&GearRule::default()), Ok(14));
+    assert_eq!(schematic.gear_ratios_sum(), Ok(14));
+}
+
+#[test]
+fn gears_with_more_numbers() {
+    // The first `*` touches 2, 3 and 5, the `#` 4 and 6, the last `*` 7
+    assert_eq!(sum_with("*", "2", Reducer::Product), 0);
+    assert_eq!(sum_with("*", "3", Reducer::Product), 30);
+    assert_eq!(sum_with("*", "1..", Reducer::Product), 37);
+}
+
+#[test]
+fn other_symbols_and_sums() {
+    assert_eq!(sum_with("#", "2", Reducer::Product), 24);
+    assert_eq!(sum_with("*#", "2..=3", Reducer::Product), 30 + 24);
+    assert_eq!(sum_with("*#", "..", Reducer::Sum), 10 + 10 + 7);
+}
+
+#[test]
+fn arities() {
+    assert_eq!(day_3::parse_arity("2"), Ok(2..=2));
+    assert_eq!(day_3::parse_arity("2..=4"), Ok(2..=4));
+    assert_eq!(day_3::parse_arity("2..5"), Ok(2..=4));
+    assert_eq!(day_3::parse_arity("3.."), Ok(3..=usize::MAX));
+    assert_eq!(day_3::parse_arity("..=1"), Ok(1..=1));
+    assert_eq!(day_3::parse_arity(".."), Ok(1..=usize::MAX));
+
+    // Symbols touching no number are never gears
+    assert!(day_3::parse_arity("0").is_err());
+    assert!(day_3::parse_arity("0..").is_err());
+    assert!(day_3::parse_arity("0..=2").is_err());
+
+    assert!(day_3::parse_arity("3..2").is_err());
+    assert!(day_3::parse_arity("..0").is_err());
+    assert!(day_3::parse_arity("two").is_err());
+}
+
+#[test]
+fn reducers() {
+    assert_eq!("product".parse(), Ok(Reducer::Product));
+    assert_eq!("sum".parse(), Ok(Reducer::Sum));
+    assert!("max".parse::<Reducer>().is_err());
+}
+
+#[test]
+fn gear_parts_touching_another_symbol() {
+    // 1 also touches the `#`, and still meshes with the `*`
+    let schematic: Schematic = "#1.\n.*2\n3..".parse().unwrap();
+    let rule = GearRule {
+        symbols: vec!['*'],
+        arity: 3..=3,
+        reducer: Reducer::Product,
+    };
+
+    assert_eq!(schematic.gear_ratios_sum_with(&rule), Ok(6));
+}
+
+#[test]
+fn ratios_too_big() {
+    // Six 4 digit numbers make a ratio of 24 digits
+    let schematic: Schematic = "9999.9999\n9999*9999\n9999.9999\n".parse().unwrap();
+    let rule = GearRule {
+        symbols: vec!['*'],
+        arity: 6..=6,
+        reducer: Reducer::Product,
+    };
+
+    let error = schematic.gear_ratios_sum_with(&rule).unwrap_err();
+    assert_eq!(error.message, "gear ratio too big");
+    assert_eq!((error.line, error.column), (Some(2), 4));
+    assert_eq!(error.token, "*");
+
+    // Their sum is fine
+    let rule = GearRule {
+        reducer: Reducer::Sum,
+        ..rule
+    };
+    assert_eq!(schematic.gear_ratios_sum_with(&rule), Ok(6 * 9999));
+}
@@ -0,0 +1,57 @@
+use day_3::{Schematic, Token, TokenValue};
+
+#[test]
+fn ring_stays_in_the_grid() {
+    let token = Token {
+        value: TokenValue::Number(12),
+        start: 0,
+        length: 2,
+        line_number: 0,
+    };
+
+    let ring: Vec<_> = token.ring(5, 3).collect();
+    assert_eq!(ring, vec![(0, 2), (1, 0), (1, 1), (1, 2)]);
+}
+
+#[test]
+fn symbols_are_indexed_by_cell() {
+    let schematic: Schematic = "467..\n...*.\n..35#".parse().unwrap();
+
+    assert_eq!(schematic.symbol_index[(1, 3)], Some(1));
+    assert_eq!(schematic.symbol_index[(2, 4)], Some(3));
+    assert_eq!(schematic.symbol_index[(0, 0)], None);
+
+    let symbols: Vec<char> = schematic
+        .adjacent_symbols(&schematic.tokens[2])
+        .map(|token| match token.value {
+            TokenValue::Symbol(c) => c,
+            TokenValue::Number(_) => '?',
+        })
+        .collect();
+    assert_eq!(symbols, vec!['*', '#']);
+}
+
+#[test]
+fn large_schematic() {
+    // Every 10 by 3 block has a gear between two numbers, and a number
+    // touching nothing
+    let block = ["10.....99.", ".*........", ".20......."];
+    let width = 1000;
+    let blocks = 400;
+
+    let rows: String = block
+        .iter()
+        .map(|row| row.repeat(width / 10) + "\n")
+        .collect();
+    let schematic: Schematic = rows.repeat(blocks).parse().unwrap();
+
+    let parts = schematic.part_numbers();
+    let gears = width / 10 * blocks;
+
+    assert_eq!(parts.len(), 2 * gears);
+    assert_eq!(
+        parts.iter().filter_map(|part| part.number()).sum::<usize>(),
+        30 * gears
+    );
+    assert_eq!(schematic.gear_ratios_sum(), Ok(200 * gears));
+}
@@ -0,0 +1,20 @@
+use day_3::Schematic;
+
+#[test]
+fn numbers_too_big_are_reported() {
+    let rows = format!("{}\né.1234567890123456789012345*\n", ".".repeat(28));
+    let error = rows.parse::<Schematic>().unwrap_err();
+
+    assert_eq!(error.message, "number too big");
+    assert_eq!(error.line, Some(2));
+    assert_eq!(error.token, "1234567890123456789012345");
+    // `é` takes two bytes
+    assert_eq!(error.column, 3);
+}
+
+#[test]
+fn largest_number() {
+    let schematic: Schematic = format!("{}*", usize::MAX).parse().unwrap();
+
+    assert_eq!(schematic.part_numbers().len(), 1);
+}
@@ -0,0 +1,125 @@
+//! Day 4: Scratchcards
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Solution};
+
+/// A scratchcard with its winning numbers and the numbers you have
+#[derive(Debug, Default)]
+pub struct Card {
+    pub id: usize,
+    pub winning_numbers: HashSet<usize>,
+    pub owned_numbers: HashSet<usize>,
+}
+
+impl Card {
+    /// Numbers you have that are also winning numbers
+    pub fn owned_winning_numbers(&self) -> Vec<usize> {
+        self.winning_numbers
+            .intersection(&self.owned_numbers)
+            .copied()
+            .collect()
+    }
+
+    pub fn count_owned_winning_numbers(&self) -> usize {
+        self.owned_winning_numbers().len()
+    }
+
+    /// Points the card is worth: 1 for the first match, doubled for every other one
+    pub fn calculate_worth(&self) -> usize {
+        let winners = self.count_owned_winning_numbers();
+
+        if winners == 0 {
+            0
+        } else {
+            1 << (winners - 1)
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    /// Parses a string like "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+    fn from_str(string: &str) -> Result<Card, Self::Err> {
+        let Some((id_slice, numbers_slice)) = string.split_once(':') else {
+            return Err(ParseError::at_end("expected `:` after the card id", string));
+        };
+
+        let Some((_, id_slice)) = id_slice.split_once(' ') else {
+            return Err(ParseError::at_token(
+                "expected `Card <id>:`",
+                string,
+                id_slice,
+            ));
+        };
+
+        let id: usize = aoc_common::parse_token(string, id_slice.trim(), "invalid card id")?;
+
+        let Some((winning_numbers_slice, owned_numbers_slice)) = numbers_slice.split_once('|')
+        else {
+            return Err(ParseError::at_end(
+                "expected `|` between the winning and owned numbers",
+                string,
+            ));
+        };
+
+        let winning_numbers: HashSet<usize> = HashSet::from_iter(
+            aoc_common::parse_numbers(winning_numbers_slice)
+                .map_err(|error| error.within(string, winning_numbers_slice))?,
+        );
+        let owned_numbers: HashSet<usize> = HashSet::from_iter(
+            aoc_common::parse_numbers(owned_numbers_slice)
+                .map_err(|error| error.within(string, owned_numbers_slice))?,
+        );
+
+        Ok(Card {
+            id,
+            winning_numbers,
+            owned_numbers,
+        })
+    }
+}
+
+/// Total number of cards you end up with when every match wins a copy of the
+/// following cards
+pub fn cards_win_more_cards(cards: &[Card]) -> usize {
+    let mut cards_count: Vec<usize> = cards.iter().map(|_| 1).collect();
+
+    for (index, card) in cards.iter().enumerate() {
+        let winner_count = card.count_owned_winning_numbers();
+        let multiplier = cards_count[index];
+
+        let index = index + 1;
+        for count in cards_count.iter_mut().skip(index).take(winner_count) {
+            *count += multiplier;
+        }
+    }
+
+    aoc_common::debug!("cards count: {:?}", cards_count);
+    cards_count.iter().sum()
+}
+
+/// Solution to both parts of day 4
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+    type Input = Vec<Card>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::parse_lines(input)
+    }
+
+    fn part1(cards: &Self::Input) -> Result<usize, ParseError> {
+        Ok(cards.iter().map(|card| card.calculate_worth()).sum())
+    }
+
+    fn part2(cards: &Self::Input) -> Result<usize, ParseError> {
+        Ok(cards_win_more_cards(cards))
+    }
+}
@@ -0,0 +1,263 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use rayon::prelude::*;
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Solution};
+
+/// Maps `length` numbers starting at `source` to the ones starting at `target`
+#[derive(Debug)]
+pub struct TransformRange {
+    pub source: usize,
+    pub target: usize,
+    pub length: usize,
+}
+
+impl TransformRange {
+    /// Whether `index` is in the source range
+    pub fn includes(&self, index: usize) -> bool {
+        index >= self.source && index - self.source < self.length
+    }
+
+    /// Transforms a number to the defined range if its included in it.
+    /// If not, returns the initial number
+    pub fn transform(&self, number: usize) -> usize {
+        if self.includes(number) {
+            number - self.source + self.target
+        } else {
+            number
+        }
+    }
+}
+impl FromStr for TransformRange {
+    type Err = ParseError;
+
+    /// Parse a string like "target source length" into a Range
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        if parts.len() != 3 {
+            return Err(ParseError::at_token(
+                "expected `<target> <source> <length>`",
+                s,
+                s,
+            ));
+        }
+
+        let target = aoc_common::parse_token(s, parts[0], "invalid target")?;
+        let source = aoc_common::parse_token(s, parts[1], "invalid source")?;
+        let length = aoc_common::parse_token(s, parts[2], "invalid length")?;
+
+        if length == 0 {
+            return Err(ParseError::at_token(
+                "expected a positive length",
+                s,
+                parts[2],
+            ));
+        }
+
+        Ok(TransformRange {
+            source,
+            target,
+            length,
+        })
+    }
+}
+
+/// Maps numbers of one category (`from`) to another one (`to`)
+#[derive(Debug)]
+pub struct Mapper {
+    pub from: String,
+    pub to: String,
+    pub ranges: Vec<TransformRange>,
+}
+
+impl Mapper {
+    /// Transforms a number with the first range that includes it
+    pub fn transform(&self, number: usize) -> usize {
+        let mut result = number;
+
+        for range in &self.ranges {
+            if range.includes(result) {
+                result = range.transform(result);
+                break;
+            }
+        }
+        result
+    }
+}
+
+impl FromStr for Mapper {
+    type Err = ParseError;
+
+    /// Parse a string like:
+    /// <form>-to-<to> map:
+    /// <range1>
+    /// <range2>
+    /// ...
+    /// Line numbers of the errors are relative to the string
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+
+        let Some(&header) = lines.first() else {
+            return Err(ParseError::at_end("expected `<from>-to-<to> map:`", ""));
+        };
+
+        let (from, to) = header
+            .strip_suffix(" map:")
+            .and_then(|name| name.split_once("-to-"))
+            .ok_or_else(|| ParseError::at_token("expected `<from>-to-<to> map:`", header, header))
+            .map_err(|error| error.at_line(1))?;
+
+        if lines.len() < 2 {
+            return Err(ParseError::at_end("expected at least one range", header).at_line(1));
+        }
+
+        let from = from.to_string();
+        let to = to.to_string();
+
+        let ranges: Vec<TransformRange> = lines[1..]
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse::<TransformRange>()
+                    .map_err(|error| error.at_line(index + 2))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Mapper { from, to, ranges })
+    }
+}
+
+/// Parses every map of the almanac. Maps are separated by blank lines
+pub fn get_mappers_from_lines(lines: &[&str]) -> Result<Vec<Mapper>, ParseError> {
+    aoc_common::numbered_blocks(lines)
+        .iter()
+        .map(|(first_line, text)| {
+            text.parse()
+                .map_err(|error: ParseError| error.offset_lines(*first_line))
+        })
+        .collect()
+}
+
+/// Parses the numbers after `seeds:`. There must be at least one seed, and
+/// they must come in pairs of a start and a positive length, which is how
+/// part 2 reads them
+fn parse_seeds(line: &str, seeds: &str) -> Result<Vec<usize>, ParseError> {
+    let tokens: Vec<&str> = seeds.split_whitespace().collect();
+    let numbers: Vec<usize> =
+        aoc_common::parse_numbers(seeds).map_err(|error| error.within(line, seeds))?;
+
+    if numbers.is_empty() {
+        return Err(ParseError::at_end("expected at least one seed", line));
+    }
+
+    if !numbers.len().is_multiple_of(2) {
+        return Err(ParseError::at_token(
+            "expected seeds as `<start> <length>` pairs, this one has no length",
+            line,
+            tokens[tokens.len() - 1],
+        ));
+    }
+
+    if let Some(index) = (1..numbers.len()).step_by(2).find(|&i| numbers[i] == 0) {
+        return Err(ParseError::at_token(
+            "expected a positive length",
+            line,
+            tokens[index],
+        ));
+    }
+
+    Ok(numbers)
+}
+
+/// The seeds to be planted and the maps from seed to location
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub mappers: Vec<Mapper>,
+}
+
+/// Error of the parts when there are no seeds, or an empty range of them
+fn no_seeds() -> ParseError {
+    ParseError::new("expected at least one seed", "", 0)
+}
+
+/// Solution to both parts of day 5
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+    type Input = Almanac;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = aoc_common::lines(input);
+        let first_line = lines.first().copied().unwrap_or_default();
+
+        let Some((_, seeds)) = first_line.split_once(':') else {
+            return Err(ParseError::at_end("expected `seeds: <numbers>`", first_line).at_line(1));
+        };
+
+        let seeds = parse_seeds(first_line, seeds).map_err(|error| error.at_line(1))?;
+
+        // Line numbers are relative to the second line
+        let mappers: Vec<Mapper> =
+            get_mappers_from_lines(&lines[1..]).map_err(|error| error.offset_lines(1))?;
+
+        Ok(Almanac { seeds, mappers })
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<usize, ParseError> {
+        let transformed_seeds: Vec<usize> = almanac
+            .seeds
+            .iter()
+            .map(|&seed| {
+                // print!("{seed} -> ");
+                let mut result: usize = seed;
+
+                for mapper in &almanac.mappers {
+                    result = mapper.transform(result);
+                    // print!("{result} -> ");
+                }
+
+                // println!("{result}");
+                result
+            })
+            .collect();
+
+        transformed_seeds.into_iter().min().ok_or_else(no_seeds)
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<usize, ParseError> {
+        // PERF: The second answer is really slow
+        let seed_ranges = almanac.seeds.par_iter().chunks(2).map(|chunk| {
+            let start = *chunk[0];
+            let length = *chunk[1];
+            start..(start + length)
+        });
+
+        let lowest: Option<Vec<usize>> = seed_ranges
+            .map(|seeds| {
+                seeds
+                    .into_par_iter()
+                    .map(|seed| {
+                        let mut result: usize = seed;
+
+                        for mapper in &almanac.mappers {
+                            result = mapper.transform(result);
+                            // print!("{result} -> ");
+                        }
+
+                        // println!("{result}");
+                        result
+                    })
+                    .min()
+            })
+            .collect();
+
+        lowest
+            .and_then(|lowest| lowest.into_iter().min())
+            .ok_or_else(no_seeds)
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_common::{ParseError, Solution};
+use day_5::{Day5, Mapper, TransformRange};
+
+fn parse_error(input: &str) -> ParseError {
+    Day5::parse(input)
+        .err()
+        .expect("the almanac should be rejected")
+}
+
+#[test]
+fn seeds_come_in_pairs() {
+    let error = parse_error("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n");
+
+    assert_eq!(error.line, Some(1));
+    assert_eq!(error.token, "55");
+    assert_eq!(error.column, 13);
+}
+
+#[test]
+fn at_least_one_seed() {
+    let error = parse_error("seeds:\n\nseed-to-soil map:\n50 98 2\n");
+
+    assert_eq!(error.line, Some(1));
+    assert_eq!(error.column, 6);
+}
+
+#[test]
+fn lengths_are_positive() {
+    let error = parse_error("seeds: 79 0\n\nseed-to-soil map:\n50 98 2\n");
+    assert_eq!((error.line, error.token.as_str()), (Some(1), "0"));
+    assert_eq!(error.column, 10);
+
+    let error = parse_error("seeds: 79 14\n\nseed-to-soil map:\n50 0 0\n");
+    assert_eq!((error.line, error.token.as_str()), (Some(4), "0"));
+    assert_eq!(error.column, 5);
+}
+
+#[test]
+fn ranges_starting_at_zero() {
+    let range: TransformRange = "50 0 2".parse().unwrap();
+
+    assert!(range.includes(0));
+    assert!(range.includes(1));
+    assert!(!range.includes(2));
+    assert_eq!(range.transform(1), 51);
+}
+
+#[test]
+fn empty_map() {
+    let Err(error) = "".parse::<Mapper>() else {
+        panic!("there is no header");
+    };
+
+    assert_eq!(error.message, "expected `<from>-to-<to> map:`");
+    assert_eq!(error.column, 0);
+}
@@ -0,0 +1,104 @@
+//! Day 6: Wait For It
+
+use aoc_common::{math, ParseError, Solution};
+
+/// First and last hold times that travel at least `distance`
+pub fn find_hold_time_range(total_time: usize, distance: usize) -> (usize, usize) {
+    // distance = (total_time - hold_time) * hold_time
+    // 0 = hold_time^2 - total_time * hold_time + distance
+    let Some([x1, x2]) = math::quadratic_roots(1, -(total_time as i64), distance as i64) else {
+        // Not even holding for half the race gets that far
+        return (1, 0);
+    };
+
+    aoc_common::debug!("x1: {:?}, x2: {:?}", x1, x2);
+    (x1.ceil as usize, x2.floor as usize)
+}
+
+/// Number of hold times that travel at least `distance`
+pub fn get_possible_hold_times_count(total_time: usize, distance: usize) -> usize {
+    let (min, max) = find_hold_time_range(total_time, distance);
+
+    (max + 1).saturating_sub(min)
+}
+
+/// Product of the number of ways to win each race
+pub fn get_winning_solution(times: &[usize], distances: &[usize]) -> usize {
+    let winning_solutions = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(t, d)| get_possible_hold_times_count(*t, *d + 1))
+        .collect::<Vec<usize>>();
+
+    winning_solutions.iter().product::<usize>()
+}
+
+/// The race times and record distances, read both as separate races and as
+/// the single race written with bad kerning
+pub struct Races {
+    pub times: Vec<usize>,
+    pub distances: Vec<usize>,
+    pub time: usize,
+    pub distance: usize,
+}
+
+/// Parses a line like "Time:      7  15   30" into its numbers, and the number
+/// made of all of its digits
+fn parse_races_line(line: &str, label: &str) -> Result<(Vec<usize>, usize), ParseError> {
+    let Some(numbers) = line.strip_prefix(label).and_then(|s| s.strip_prefix(':')) else {
+        return Err(ParseError::at_token(
+            format!("expected `{label}:`"),
+            line,
+            line,
+        ));
+    };
+
+    let separate =
+        aoc_common::parse_numbers(numbers).map_err(|error| error.within(line, numbers))?;
+
+    let kerning_corrected: String = numbers.chars().filter(|c| c.is_ascii_digit()).collect();
+    let kerning_corrected = aoc_common::parse_token(numbers, &kerning_corrected, "number too big")
+        .map_err(|error| error.within(line, numbers.trim()))?;
+
+    Ok((separate, kerning_corrected))
+}
+
+/// Solution to both parts of day 6
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+    type Input = Races;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = aoc_common::lines(input);
+
+        let time_line = lines.first().copied().unwrap_or_default();
+        let distance_line = lines.get(1).copied().unwrap_or_default();
+
+        let (times, time) = parse_races_line(time_line, "Time").map_err(|e| e.at_line(1))?;
+        let (distances, distance) =
+            parse_races_line(distance_line, "Distance").map_err(|e| e.at_line(2))?;
+
+        Ok(Races {
+            times,
+            distances,
+            time,
+            distance,
+        })
+    }
+
+    fn part1(races: &Self::Input) -> Result<usize, ParseError> {
+        Ok(get_winning_solution(&races.times, &races.distances))
+    }
+
+    fn part2(races: &Self::Input) -> Result<usize, ParseError> {
+        Ok(get_possible_hold_times_count(
+            races.time,
+            races.distance + 1,
+        ))
+    }
+}
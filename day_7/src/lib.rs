@@ -0,0 +1,357 @@
+//! Day 7: Camel Cards
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::{ParseError, Solution};
+
+/// A hand of five cards classified by its type
+#[derive(Debug, PartialEq)]
+pub enum HandType {
+    // * all five cards have the same label: `AAAAA`
+    FiveOfAKind(String),
+    // four cards have the same label and one card has a different label: `AA8AA`
+    FourOfAKind(String),
+    // three cards have the same label, and the remaining two cards share a different label: `23332`
+    FullHouse(String),
+    // three cards have the same label, and the remaining two cards are each different from any other card in the hand: `TTT98`
+    ThreeOfAKind(String),
+    // two cards share one label, two other cards share a second label, and the remaining card has a third label: `23432`
+    TwoPair(String),
+    // two cards share one label, and the other three cards have a different label from the pair and each other: `A23A4`
+    OnePair(String),
+    // where all cards' labels are distinct: `23456`
+    HighCard(String),
+}
+
+/// Checks that every card of the hand is valid
+fn validate_cards(s: &str) -> Result<(), ParseError> {
+    const CARDS: &str = "23456789TJQKA";
+
+    match s.char_indices().find(|(_, c)| !CARDS.contains(*c)) {
+        Some((column, card)) => {
+            Err(ParseError::new(format!("invalid card `{card}`"), card, column).with_source_line(s))
+        }
+        None => Ok(()),
+    }
+}
+
+/// The cards of a hand, checking that there are 5 valid ones
+fn hand_cards(s: &str) -> Result<Vec<char>, ParseError> {
+    let chars: Vec<char> = s.trim().chars().collect();
+
+    if chars.len() != 5 {
+        return Err(ParseError::at_token(
+            format!("a hand has 5 cards, found {}", chars.len()),
+            s,
+            s.trim(),
+        ));
+    }
+
+    validate_cards(s)?;
+
+    Ok(chars)
+}
+
+impl HandType {
+    /// Strength of the hand type, 0 being the strongest
+    pub fn discriminant(&self) -> usize {
+        match self {
+            Self::FiveOfAKind(_) => 0,
+            Self::FourOfAKind(_) => 1,
+            Self::FullHouse(_) => 2,
+            Self::ThreeOfAKind(_) => 3,
+            Self::TwoPair(_) => 4,
+            Self::OnePair(_) => 5,
+            Self::HighCard(_) => 6,
+        }
+    }
+
+    /// The cards of the hand, in order
+    pub fn cards(&self) -> &String {
+        match self {
+            Self::FiveOfAKind(s) => s,
+            Self::FourOfAKind(s) => s,
+            Self::FullHouse(s) => s,
+            Self::ThreeOfAKind(s) => s,
+            Self::TwoPair(s) => s,
+            Self::OnePair(s) => s,
+            Self::HighCard(s) => s,
+        }
+    }
+
+    /// Parses a hand where `J` cards are jokers
+    pub fn from_str_with_joker(s: &str) -> Result<Self, ParseError> {
+        const JOKER: char = 'J';
+
+        let cards = hand_cards(s)?;
+
+        if !cards.contains(&JOKER) {
+            return Self::from_str(s);
+        }
+
+        let chars: Vec<char> = cards.iter().copied().filter(|&c| c != JOKER).collect();
+        let joker_count = cards.len() - chars.len();
+
+        let mut chars_count: HashMap<char, usize> =
+            chars.iter().fold(HashMap::new(), |mut acc, &c| {
+                let count = acc.entry(c).or_insert(0);
+                *count += 1;
+                acc
+            });
+
+        let owned: String = s.to_string();
+
+        let greatest_entry = chars_count
+            .iter_mut()
+            .max_by(|count_a, count_b| count_a.1.cmp(&count_b.1));
+
+        if let Some(entry) = greatest_entry {
+            *entry.1 += joker_count;
+        } else {
+            return Ok(HandType::FiveOfAKind(owned));
+        }
+
+        match chars_count.len() {
+            1 => Ok(HandType::FiveOfAKind(owned)),
+            2 => {
+                if chars_count.values().any(|&v| v == 4) {
+                    Ok(HandType::FourOfAKind(owned))
+                } else {
+                    Ok(HandType::FullHouse(owned))
+                }
+            }
+            3 => {
+                if chars_count.values().any(|&v| v == 3) {
+                    Ok(HandType::ThreeOfAKind(owned))
+                } else {
+                    Ok(HandType::TwoPair(owned))
+                }
+            }
+            4 => Ok(HandType::OnePair(owned)),
+            5 => Ok(HandType::HighCard(owned)),
+            _ => Err(ParseError::at_token("invalid hand", s, s)),
+        }
+    }
+
+    /// Compares two hands where `J` cards are the weakest ones
+    pub fn partial_cmp_with_joker(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        const CARD_ORDER: [char; 13] = [
+            'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+        ];
+
+        let discriminant_self = self.discriminant();
+        let discriminant_other = other.discriminant();
+
+        if discriminant_self == discriminant_other {
+            let self_cards = self.cards();
+            let other_cards = other.cards();
+
+            let order = self_cards.chars().zip(other_cards.chars()).fold(
+                std::cmp::Ordering::Equal,
+                |acc, (self_card, other_card)| {
+                    if acc == std::cmp::Ordering::Equal {
+                        let self_card_index = CARD_ORDER
+                            .iter()
+                            .position(|&c| c == self_card)
+                            .expect("Invalid card");
+
+                        let other_card_index = CARD_ORDER
+                            .iter()
+                            .position(|&c| c == other_card)
+                            .expect("Invalid card");
+
+                        other_card_index.cmp(&self_card_index)
+                    } else {
+                        acc
+                    }
+                },
+            );
+
+            Some(order)
+        } else {
+            discriminant_self.partial_cmp(&discriminant_other)
+        }
+    }
+}
+
+impl FromStr for HandType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = hand_cards(s)?;
+
+        let chars_count: HashMap<char, usize> = chars.iter().fold(HashMap::new(), |mut acc, &c| {
+            let count = acc.entry(c).or_insert(0);
+            *count += 1;
+            acc
+        });
+
+        let owned: String = s.to_string();
+
+        match chars_count.len() {
+            1 => Ok(HandType::FiveOfAKind(owned)),
+            2 => {
+                if chars_count.values().any(|&v| v == 4) {
+                    Ok(HandType::FourOfAKind(owned))
+                } else {
+                    Ok(HandType::FullHouse(owned))
+                }
+            }
+            3 => {
+                if chars_count.values().any(|&v| v == 3) {
+                    Ok(HandType::ThreeOfAKind(owned))
+                } else {
+                    Ok(HandType::TwoPair(owned))
+                }
+            }
+            4 => Ok(HandType::OnePair(owned)),
+            5 => Ok(HandType::HighCard(owned)),
+            _ => Err(ParseError::at_token("invalid hand", s, s)),
+        }
+    }
+}
+
+impl std::cmp::PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        const CARD_ORDER: [char; 13] = [
+            '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+        ];
+
+        let discriminant_self = self.discriminant();
+        let discriminant_other = other.discriminant();
+
+        if discriminant_self == discriminant_other {
+            let self_cards = self.cards();
+            let other_cards = other.cards();
+
+            let order = self_cards.chars().zip(other_cards.chars()).fold(
+                std::cmp::Ordering::Equal,
+                |acc, (self_card, other_card)| {
+                    if acc == std::cmp::Ordering::Equal {
+                        let self_card_index = CARD_ORDER
+                            .iter()
+                            .position(|&c| c == self_card)
+                            .expect("Invalid card");
+
+                        let other_card_index = CARD_ORDER
+                            .iter()
+                            .position(|&c| c == other_card)
+                            .expect("Invalid card");
+
+                        other_card_index.cmp(&self_card_index)
+                    } else {
+                        acc
+                    }
+                },
+            );
+
+            Some(order)
+        } else {
+            discriminant_self.partial_cmp(&discriminant_other)
+        }
+    }
+}
+
+/// A hand and the amount bid on it
+#[derive(Debug, PartialEq)]
+pub struct Bid(pub HandType, pub usize);
+
+impl Bid {
+    pub fn from_str_with_joker(s: &str) -> Result<Self, ParseError> {
+        let parts = s
+            .split_whitespace()
+            .map(|p| p.trim())
+            .collect::<Vec<&str>>();
+
+        if parts.len() != 2 {
+            return Err(ParseError::at_token("expected `<hand> <bid>`", s, s));
+        }
+
+        let hand: HandType =
+            HandType::from_str_with_joker(parts[0]).map_err(|error| error.within(s, parts[0]))?;
+        let ammount: usize = aoc_common::parse_token(s, parts[1], "invalid bid ammount")?;
+
+        Ok(Bid(hand, ammount))
+    }
+    pub fn partial_cmp_with_joker(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp_with_joker(&other.0)
+    }
+}
+
+impl std::cmp::PartialOrd for Bid {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl FromStr for Bid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s
+            .split_whitespace()
+            .map(|p| p.trim())
+            .collect::<Vec<&str>>();
+
+        if parts.len() != 2 {
+            return Err(ParseError::at_token("expected `<hand> <bid>`", s, s));
+        }
+
+        let hand: HandType = parts[0]
+            .parse()
+            .map_err(|error: ParseError| error.within(s, parts[0]))?;
+        let ammount: usize = aoc_common::parse_token(s, parts[1], "invalid bid ammount")?;
+
+        Ok(Bid(hand, ammount))
+    }
+}
+
+/// Every bid, parsed with and without the joker rule
+pub struct CamelCards {
+    pub bids: Vec<Bid>,
+    pub joker_bids: Vec<Bid>,
+}
+
+/// Total winnings of the bids once sorted with `compare`
+fn total_winnings<F>(bids: &[Bid], compare: F) -> usize
+where
+    F: Fn(&Bid, &Bid) -> Option<std::cmp::Ordering>,
+{
+    let mut bids: Vec<&Bid> = bids.iter().collect();
+    bids.sort_by(|a, b| compare(b, a).unwrap());
+
+    bids.iter()
+        .enumerate()
+        .map(|(i, bid)| bid.1 * (i + 1))
+        .sum()
+}
+
+/// Solution to both parts of day 7
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+    type Input = CamelCards;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(CamelCards {
+            bids: aoc_common::parse_lines(input)?,
+            joker_bids: aoc_common::parse_lines_with(input, Bid::from_str_with_joker)?,
+        })
+    }
+
+    fn part1(cards: &Self::Input) -> Result<usize, ParseError> {
+        Ok(total_winnings(&cards.bids, Bid::partial_cmp))
+    }
+
+    fn part2(cards: &Self::Input) -> Result<usize, ParseError> {
+        Ok(total_winnings(
+            &cards.joker_bids,
+            Bid::partial_cmp_with_joker,
+        ))
+    }
+}
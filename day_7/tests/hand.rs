@@ -0,0 +1,32 @@
+use day_7::HandType;
+
+#[test]
+fn jokers_count_towards_the_hand_size() {
+    let error = HandType::from_str_with_joker("JJ").unwrap_err();
+    assert_eq!(error.message, "a hand has 5 cards, found 2");
+
+    assert_eq!(
+        HandType::from_str_with_joker("JJJJJ"),
+        Ok(HandType::FiveOfAKind("JJJJJ".to_string()))
+    );
+}
+
+#[test]
+fn hands_with_jokers_only_have_valid_cards() {
+    let error = HandType::from_str_with_joker("J2X45").unwrap_err();
+
+    assert_eq!(error.token, "X");
+    assert_eq!(error.column, 2);
+}
+
+#[test]
+fn jokers_are_counted_by_card() {
+    // `é` is two bytes long, but still a single (invalid) card
+    let error = HandType::from_str_with_joker("JéJ23").unwrap_err();
+    assert_eq!(error.token, "é");
+
+    assert_eq!(
+        HandType::from_str_with_joker("KTJJT"),
+        Ok(HandType::FourOfAKind("KTJJT".to_string()))
+    );
+}
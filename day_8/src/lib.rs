@@ -0,0 +1,266 @@
+//! Day 8: Haunted Wasteland
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::{math, ParseError, Solution};
+
+/// A left/right instruction
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::new(format!("invalid direction `{c}`"), c, 0)),
+        }
+    }
+}
+
+/// The nodes reached by going left or right
+#[derive(Debug)]
+pub struct BinaryNode {
+    pub left: String,
+    pub right: String,
+}
+
+impl FromStr for BinaryNode {
+    type Err = ParseError;
+
+    /// Parses a string of the form "(BBB, CCC)" into a BinaryNode
+    /// (BBB, CCC)
+    /// (DDD, EEE)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((left, right)) = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .and_then(|s| s.split_once(','))
+        else {
+            return Err(ParseError::at_token("expected `(<left>, <right>)`", s, s));
+        };
+
+        let left = left.trim();
+        let right = right.trim();
+
+        Ok(BinaryNode {
+            left: left.to_string(),
+            right: right.to_string(),
+        })
+    }
+}
+
+pub type NodeMap = HashMap<String, BinaryNode>;
+
+/// Parses a string of the form "AAA = (BBB CCC)" into a NodeMap entry
+pub fn entry_from_str(map: &mut NodeMap, s: &str) -> Result<String, ParseError> {
+    let Some((key, value)) = s.split_once(" = ") else {
+        return Err(ParseError::at_token(
+            "expected `<node> = (<left>, <right>)`",
+            s,
+            s,
+        ));
+    };
+
+    let key = key.trim().to_string();
+    let value = value.trim();
+
+    let node = BinaryNode::from_str(value).map_err(|error| error.within(s, value))?;
+
+    map.insert(key.clone(), node);
+
+    Ok(key)
+}
+
+/// Checks that the nodes of every `<node> = (<left>, <right>)` line are
+/// defined. Errors point at the first name that isn't
+fn check_links(nodes: &NodeMap, line: &str) -> Result<(), ParseError> {
+    let Some((_, value)) = line.split_once(" = ") else {
+        return Ok(());
+    };
+
+    let node = BinaryNode::from_str(value.trim())?;
+
+    // The left name comes first and the right one last
+    let names = [
+        (&node.left, value.find(node.left.as_str())),
+        (&node.right, value.rfind(node.right.as_str())),
+    ];
+
+    for (name, column) in names {
+        if let (false, Some(column)) = (nodes.contains_key(name), column) {
+            let token = &value[column..column + name.len()];
+            return Err(ParseError::at_token(
+                format!("no node named `{name}`"),
+                line,
+                token,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Steps needed to go from `start` to a node matching `is_target`, following the
+/// directions over and over. Fails if there is no `start` node, or if the path
+/// never gets to a target
+pub fn get_path_steps<IsTargetFn>(
+    nodes: &NodeMap,
+    directions: &[Direction],
+    start: &str,
+    is_target: IsTargetFn,
+) -> Result<usize, ParseError>
+where
+    IsTargetFn: Fn(&str) -> bool,
+{
+    if !nodes.contains_key(start) {
+        return Err(ParseError::new(
+            format!("no node named `{start}`"),
+            start,
+            0,
+        ));
+    }
+
+    // After a step from every node at every direction, the path can only be
+    // going around in circles
+    let limit = nodes.len().saturating_mul(directions.len());
+
+    let mut current_key = start;
+    let mut count: usize = 0;
+
+    for direction in directions.iter().cycle() {
+        if count == limit {
+            return Err(ParseError::new(
+                format!("the path from `{start}` never ends"),
+                start,
+                0,
+            ));
+        }
+
+        let node = nodes
+            .get(current_key)
+            .expect("nodes only lead to defined nodes");
+
+        match direction {
+            Direction::Left => {
+                current_key = &node.left;
+            }
+            Direction::Right => {
+                current_key = &node.right;
+            }
+        }
+
+        count += 1;
+
+        if is_target(current_key) {
+            break;
+        }
+    }
+
+    Ok(count)
+}
+
+/// The instructions and the network of nodes to follow them through
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub nodes: NodeMap,
+    pub keys: Vec<String>,
+}
+
+/// Solution to both parts of day 8
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+    type Input = Network;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<(usize, &str)> = aoc_common::numbered_lines(input)
+            .into_iter()
+            .filter(|(_, line)| !line.is_empty())
+            .collect();
+
+        let Some(&(directions_line_number, directions_line)) = lines.first() else {
+            return Err(ParseError::at_end(
+                "expected the left/right instructions",
+                "",
+            ));
+        };
+
+        let mut keys: Vec<String> = Vec::new();
+        let mut nodes: HashMap<String, BinaryNode> = HashMap::new();
+
+        let directions: Vec<Direction> = directions_line
+            .char_indices()
+            .map(|(column, c)| {
+                Direction::try_from(c).map_err(|error| {
+                    error
+                        .offset_columns(column)
+                        .with_source_line(directions_line)
+                        .at_line(directions_line_number)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        for &(line_number, line) in lines[1..].iter() {
+            let key = entry_from_str(&mut nodes, line).map_err(|e| e.at_line(line_number))?;
+            keys.push(key);
+        }
+
+        for &(line_number, line) in lines[1..].iter() {
+            check_links(&nodes, line).map_err(|e| e.at_line(line_number))?;
+        }
+
+        Ok(Network {
+            directions,
+            nodes,
+            keys,
+        })
+    }
+
+    fn part1(network: &Self::Input) -> Result<usize, ParseError> {
+        let start_key = "AAA".to_string();
+        let target_key = "ZZZ".to_string();
+
+        get_path_steps(&network.nodes, &network.directions, &start_key, |key| {
+            key == target_key
+        })
+    }
+
+    fn part2(network: &Self::Input) -> Result<usize, ParseError> {
+        let start_keys: Vec<&String> = network
+            .keys
+            .iter()
+            .filter(|key| key.ends_with('A'))
+            .collect();
+
+        // Get each unique path's step count. Each path may have a different step count.
+        let steps_list: Vec<usize> = start_keys
+            .iter()
+            .map(|key| {
+                get_path_steps(&network.nodes, &network.directions, key, |key| {
+                    key.ends_with('Z')
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Find when all paths would join together.
+        // > Path of size 2: ++--++--++
+        // > Path of size 5: +++++-----
+        // The two paths join for the first time at 10, which is the least common multiple
+        let count = math::checked_lcm_all(steps_list.into_iter().map(|steps| steps as u64));
+
+        count
+            .and_then(|count| usize::try_from(count).ok())
+            .ok_or_else(|| ParseError::new("the number of steps overflows", "", 0))
+    }
+}
@@ -0,0 +1,49 @@
+use aoc_common::Solution;
+use day_8::Day8;
+
+#[test]
+fn undefined_nodes_are_reported() {
+    let error = Day8::parse("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, QQQ)\nZZZ = (ZZZ, ZZZ)\n")
+        .err()
+        .expect("QQQ is not defined");
+
+    assert_eq!(error.line, Some(4));
+    assert_eq!(error.token, "QQQ");
+    assert_eq!(error.column, 12);
+}
+
+#[test]
+fn left_node_is_checked_first() {
+    let error = Day8::parse("L\n\nAAA = (XXX, YYY)\n")
+        .err()
+        .expect("neither node is defined");
+
+    assert_eq!(error.token, "XXX");
+    assert_eq!(error.column, 7);
+}
+
+#[test]
+fn defined_later_is_fine() {
+    let network = Day8::parse("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+    assert_eq!(Day8::part1(&network), Ok(1));
+}
+
+#[test]
+fn missing_start_is_an_error_of_part_1() {
+    // Part 2 starts from the nodes ending with `A` instead
+    let network = Day8::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)\n").unwrap();
+
+    let error = Day8::part1(&network).unwrap_err();
+    assert_eq!(error.message, "no node named `AAA`");
+    assert_eq!(Day8::part2(&network), Ok(1));
+}
+
+#[test]
+fn endless_paths_are_reported() {
+    let network =
+        Day8::parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+    let error = Day8::part1(&network).unwrap_err();
+    assert_eq!(error.message, "the path from `AAA` never ends");
+}
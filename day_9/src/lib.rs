@@ -0,0 +1,69 @@
+//! Day 9: Mirage Maintenance
+
+use aoc_common::{math, ParseError, Solution};
+
+/// Predicts the next value of a sequence from its differences, `None` if
+/// it overflows
+pub fn extrapolate_next_in_sequence(sequence: &[i64]) -> Option<i64> {
+    math::extrapolate_forward(sequence)
+}
+
+/// Predicts the value before the first one of a sequence, `None` if it
+/// overflows
+pub fn extrapolate_previous_in_sequence(sequence: &[i64]) -> Option<i64> {
+    math::extrapolate_backward(sequence)
+}
+
+/// Sum of the values `extrapolate` predicts for every history. Fails if one
+/// of them or the sum overflows
+fn sum_extrapolated(
+    histories: &[Vec<i64>],
+    extrapolate: fn(&[i64]) -> Option<i64>,
+) -> Result<i64, ParseError> {
+    let mut sum: i64 = 0;
+
+    for (index, history) in histories.iter().enumerate() {
+        let overflow = |message: String| ParseError::new(message, "", 0);
+
+        let value = extrapolate(history).ok_or_else(|| {
+            overflow(format!(
+                "the value extrapolated from history {} overflows",
+                index + 1
+            ))
+        })?;
+
+        sum = sum
+            .checked_add(value)
+            .ok_or_else(|| overflow("the sum of the extrapolated values overflows".to_string()))?;
+    }
+
+    Ok(sum)
+}
+
+/// Parses a history of values like "0 3 6 9 12 15"
+pub fn parse_line(line: &str) -> Result<Vec<i64>, ParseError> {
+    aoc_common::parse_numbers(line)
+}
+
+/// Solution to both parts of day 9
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const DEFAULT_INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+    type Input = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::parse_lines_with(input, parse_line)
+    }
+
+    fn part1(numbers_list: &Self::Input) -> Result<i64, ParseError> {
+        sum_extrapolated(numbers_list, extrapolate_next_in_sequence)
+    }
+
+    fn part2(numbers_list: &Self::Input) -> Result<i64, ParseError> {
+        sum_extrapolated(numbers_list, extrapolate_previous_in_sequence)
+    }
+}
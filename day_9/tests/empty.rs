@@ -0,0 +1,10 @@
+use aoc_common::Solution;
+use day_9::Day9;
+
+#[test]
+fn empty_input_sums_to_zero() {
+    let sequences = Day9::parse("").unwrap();
+
+    assert_eq!(Day9::part1(&sequences), Ok(0));
+    assert_eq!(Day9::part2(&sequences), Ok(0));
+}
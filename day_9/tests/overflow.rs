@@ -0,0 +1,26 @@
+use aoc_common::Solution;
+use day_9::Day9;
+
+#[test]
+fn overflowing_history_is_an_error() {
+    let histories = Day9::parse("1 2 3\n9223372036854775807 -9223372036854775808\n").unwrap();
+
+    let error = Day9::part1(&histories).unwrap_err();
+    assert_eq!(
+        error.message,
+        "the value extrapolated from history 2 overflows"
+    );
+    assert!(Day9::part2(&histories).is_err());
+}
+
+#[test]
+fn overflowing_sum_is_an_error() {
+    let max = i64::MAX;
+    let histories = Day9::parse(&format!("{max} {max}\n{max} {max}\n")).unwrap();
+
+    let error = Day9::part1(&histories).unwrap_err();
+    assert_eq!(
+        error.message,
+        "the sum of the extrapolated values overflows"
+    );
+}